@@ -0,0 +1,167 @@
+use crossterm::style::{Attributes, Color};
+
+/// The content and style of a single terminal cell.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Cell {
+    pub char: char,
+    pub foreground: Option<Color>,
+    pub background: Color,
+    pub attributes: Attributes,
+}
+
+impl Cell {
+    /// Creates a new cell.
+    pub fn new(
+        char: char,
+        foreground: Option<Color>,
+        background: Color,
+        attributes: Attributes,
+    ) -> Self {
+        Self {
+            char,
+            foreground,
+            background,
+            attributes,
+        }
+    }
+
+    /// Creates an empty cell without any style.
+    pub fn empty() -> Self {
+        Self::new(' ', None, Color::Reset, Attributes::default())
+    }
+}
+
+/// A back buffer containing the cells currently visible on the terminal.
+pub struct Buffer {
+    width: u16,
+    height: u16,
+    cells: Vec<Option<Cell>>,
+}
+
+impl Buffer {
+    /// Creates a new buffer where the content of every cell is unknown.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![None; width as usize * height as usize],
+        }
+    }
+
+    /// Creates a new buffer where every cell is empty.
+    pub fn cleared(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Some(Cell::empty()); width as usize * height as usize],
+        }
+    }
+
+    /// Returns the size of the buffer.
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
+    /// Returns the cell at the position or none if it is unknown.
+    /// # Panics
+    /// Panics if the position is outside of the buffer.
+    pub fn get(&self, x: u16, y: u16) -> Option<Cell> {
+        assert!(x < self.width && y < self.height, "Position out of bounds.");
+
+        self.cells[y as usize * self.width as usize + x as usize]
+    }
+
+    /// Stores the cell and returns true if it differs from the previous one.
+    /// # Panics
+    /// Panics if the position is outside of the buffer.
+    pub fn update(&mut self, x: u16, y: u16, cell: Cell) -> bool {
+        assert!(x < self.width && y < self.height, "Position out of bounds.");
+
+        let index = y as usize * self.width as usize + x as usize;
+        let changed = self.cells[index] != Some(cell);
+
+        self.cells[index] = Some(cell);
+        changed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crossterm::style::Attribute;
+
+    #[test]
+    fn size() {
+        assert_eq!((3, 2), Buffer::new(3, 2).size());
+    }
+
+    #[test]
+    fn get() {
+        let mut buffer = Buffer::new(3, 2);
+        let cell = Cell::new('x', Some(Color::Red), Color::Blue, Attribute::Bold.into());
+
+        buffer.update(2, 1, cell);
+
+        assert_eq!(Some(cell), buffer.get(2, 1));
+        assert_eq!(None, buffer.get(1, 1));
+    }
+
+    #[test]
+    fn update_unknown() {
+        let mut buffer = Buffer::new(3, 2);
+
+        assert!(buffer.update(2, 1, Cell::empty()));
+    }
+
+    #[test]
+    fn update_cleared() {
+        let mut buffer = Buffer::cleared(3, 2);
+
+        assert!(!buffer.update(2, 1, Cell::empty()));
+        assert!(buffer.update(
+            0,
+            0,
+            Cell::new('x', None, Color::Reset, Attributes::default())
+        ));
+    }
+
+    #[test]
+    fn update_same() {
+        let mut buffer = Buffer::new(3, 2);
+        let cell = Cell::new('x', Some(Color::Red), Color::Blue, Attribute::Bold.into());
+
+        buffer.update(1, 1, cell);
+
+        assert!(!buffer.update(1, 1, cell));
+    }
+
+    #[test]
+    fn update_different() {
+        let mut buffer = Buffer::new(3, 2);
+
+        buffer.update(1, 1, Cell::empty());
+
+        assert!(buffer.update(
+            1,
+            1,
+            Cell::new('x', None, Color::Reset, Attributes::default())
+        ));
+    }
+
+    #[test]
+    fn update_keeps_other_cells() {
+        let mut buffer = Buffer::new(3, 2);
+
+        buffer.update(0, 1, Cell::empty());
+        buffer.update(1, 0, Cell::empty());
+
+        assert!(!buffer.update(0, 1, Cell::empty()));
+        assert!(buffer.update(1, 1, Cell::empty()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_out_of_bounds() {
+        Buffer::new(3, 2).update(3, 0, Cell::empty());
+    }
+}
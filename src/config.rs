@@ -0,0 +1,276 @@
+use crate::Error;
+use clap::Command;
+use std::path::PathBuf;
+use toml::{Table, Value};
+
+/// Returns the default path of the config file from the environment
+/// variables `XDG_CONFIG_HOME` or `HOME`.
+pub fn config_path(var: impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    let dir = match var("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(var("HOME")?).join(".config"),
+    };
+    Some(dir.join("wipe").join("config.toml"))
+}
+
+/// The built-in presets as TOML tables of arguments.
+const PRESETS: [(&str, &str); 3] = [
+    (
+        "matrix",
+        r##"
+            chars = "01"
+            colors = "green"
+            char-pattern = "line"
+            char-angle = 90
+            color-pattern = "dissolve"
+        "##,
+    ),
+    (
+        "sunset",
+        r##"
+            colors = "#2b1055,#d53369,#ff9a44,#fcd34d"
+            interpolation = "oklab"
+            easing = "ease-out"
+            char-pattern = "circle"
+            color-pattern = "line"
+            color-angle = 90
+        "##,
+    ),
+    (
+        "minimal",
+        r##"
+            chars = ".:"
+            colors = "gray"
+            char-pattern = "line"
+            char-segments = 1
+            char-shrink = 1
+            color-pattern = "line"
+        "##,
+    ),
+];
+
+/// The arguments which only apply to a single run on the command line.
+pub const CLI_ONLY: [&str; 6] = [
+    "config",
+    "preset",
+    "list-presets",
+    "seed",
+    "print-seed",
+    "explain",
+];
+
+/// A named list of arguments.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Preset {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+/// Converts the content of a config file to command line arguments.
+///
+/// Every key is the long name of an argument of the command,
+/// except for the `presets` table.
+pub fn config_args(content: &str, command: &Command) -> Result<Vec<String>, Error> {
+    let mut table = parse(content)?;
+    table.remove("presets");
+
+    table_args(table, command)
+}
+
+/// Returns the built-in presets followed by the presets of the config file.
+///
+/// The presets of the config file are tables in the `presets` table.
+pub fn presets(content: Option<&str>, command: &Command) -> Result<Vec<Preset>, Error> {
+    let mut presets = Vec::new();
+
+    for (name, content) in PRESETS {
+        presets.push(Preset {
+            name: name.to_string(),
+            args: table_args(parse(content)?, command)?,
+        });
+    }
+    let tables = match content.map(parse).transpose()? {
+        Some(mut table) => table.remove("presets"),
+        None => None,
+    };
+    let tables = match tables {
+        Some(Value::Table(tables)) => tables,
+        Some(_) => return Err("Invalid value of 'presets': expected a table".into()),
+        None => Table::new(),
+    };
+    for (name, table) in tables {
+        let Value::Table(table) = table else {
+            return Err(format!("Invalid preset '{}': expected a table", name).into());
+        };
+        if table.contains_key("preset") {
+            return Err(format!("Invalid preset '{}': presets can't be nested", name).into());
+        }
+        let preset = Preset {
+            args: table_args(table, command)
+                .map_err(|err| format!("Invalid preset '{}': {:?}", name, err))?,
+            name,
+        };
+        // User presets replace built-in presets with the same name.
+        presets.retain(|other| other.name != preset.name);
+        presets.push(preset);
+    }
+    Ok(presets)
+}
+
+/// Parses the content of a TOML file.
+fn parse(content: &str) -> Result<Table, Error> {
+    content
+        .parse()
+        .map_err(|err: toml::de::Error| Error::from(err.message()))
+}
+
+/// Converts a table to command line arguments.
+fn table_args(table: Table, command: &Command) -> Result<Vec<String>, Error> {
+    let mut args = Vec::new();
+
+    for (key, value) in table {
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_long() == Some(&key) && !CLI_ONLY.contains(&key.as_str()))
+            .ok_or_else(|| format!("Invalid key: '{}'", key))?;
+
+        // Flags that don't take a value are set by 'true'.
+        if !arg.get_action().takes_values() {
+            match value {
+                Value::Boolean(true) => args.push(format!("--{}", key)),
+                Value::Boolean(false) => (),
+                _ => return Err(format!("Invalid value of '{}': expected a boolean", key).into()),
+            }
+            continue;
+        }
+        let value = match value {
+            Value::Array(values) => values
+                .iter()
+                .map(format_value)
+                .collect::<Option<Vec<_>>>()
+                .map(|values| values.join(",")),
+            value => format_value(&value),
+        };
+        match value {
+            Some(value) => args.push(format!("--{}={}", key, value)),
+            None => return Err(format!("Invalid value of '{}'", key).into()),
+        }
+    }
+    Ok(args)
+}
+
+/// Formats a scalar value as an argument value.
+fn format_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Integer(value) => Some(value.to_string()),
+        Value::Float(value) => Some(value.to_string()),
+        Value::Boolean(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::env;
+    use clap::{Arg, ArgAction};
+
+    fn command() -> Command {
+        Command::new("wipe")
+            .arg(Arg::new("fps").long("fps"))
+            .arg(Arg::new("chars").long("chars"))
+            .arg(Arg::new("attributes").long("attributes"))
+            .arg(Arg::new("invert").long("invert"))
+            .arg(Arg::new("config").long("config"))
+            .arg(
+                Arg::new("alternate-screen")
+                    .long("alternate-screen")
+                    .action(ArgAction::SetTrue),
+            )
+    }
+
+    #[test]
+    fn config_path_xdg() {
+        let var = env(&[("XDG_CONFIG_HOME", "/xdg"), ("HOME", "/home/user")]);
+
+        assert_eq!(
+            Some(PathBuf::from("/xdg/wipe/config.toml")),
+            config_path(var)
+        );
+    }
+
+    #[test]
+    fn config_path_home() {
+        let var = env(&[("XDG_CONFIG_HOME", ""), ("HOME", "/home/user")]);
+
+        assert_eq!(
+            Some(PathBuf::from("/home/user/.config/wipe/config.toml")),
+            config_path(var)
+        );
+    }
+
+    #[test]
+    fn config_path_none() {
+        assert_eq!(None, config_path(env(&[])));
+    }
+
+    #[test]
+    fn config_args_values() {
+        let content = r#"
+            fps = 30
+            chars = "-=#"
+            attributes = ["dim", "bold+italic"]
+            invert = false
+        "#;
+        let expected = vec![
+            "--attributes=dim,bold+italic",
+            "--chars=-=#",
+            "--fps=30",
+            "--invert=false",
+        ];
+        assert_eq!(expected, config_args(content, &command()).unwrap());
+    }
+
+    #[test]
+    fn config_args_skips_presets() {
+        let content = r#"
+            fps = 30
+
+            [presets.calm]
+            chars = "."
+        "#;
+        assert_eq!(vec!["--fps=30"], config_args(content, &command()).unwrap());
+    }
+
+    #[test]
+    fn config_args_flag() {
+        let command = command();
+
+        assert_eq!(
+            vec!["--alternate-screen"],
+            config_args("alternate-screen = true", &command).unwrap()
+        );
+        assert!(config_args("alternate-screen = false", &command)
+            .unwrap()
+            .is_empty());
+        assert!(config_args("alternate-screen = 1", &command).is_err());
+    }
+
+    #[test]
+    fn config_args_invalid_key() {
+        assert!(config_args("duration = 1", &command()).is_err());
+        assert!(config_args("config = 'other.toml'", &command()).is_err());
+    }
+
+    #[test]
+    fn config_args_invalid_value() {
+        assert!(config_args("fps = { value = 1 }", &command()).is_err());
+        assert!(config_args("fps = [[1]]", &command()).is_err());
+    }
+
+    #[test]
+    fn config_args_invalid_toml() {
+        assert!(config_args("fps = ", &command()).is_err());
+    }
+}
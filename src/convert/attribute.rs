@@ -0,0 +1,63 @@
+use crossterm::style::Attributes;
+
+/// A trait to convert a sample to [Attributes].
+#[cfg_attr(test, mockall::automock)]
+pub trait AttributeConverter {
+    fn convert(&self, level: f32) -> Attributes;
+}
+
+/// The implementation of [AttributeConverter].
+pub struct AttributeConverterImpl {
+    attributes: Vec<Attributes>,
+}
+
+impl AttributeConverterImpl {
+    /// The attributes used for mapping.
+    pub fn new(attributes: Vec<Attributes>) -> Self {
+        Self { attributes }
+    }
+}
+
+impl AttributeConverter for AttributeConverterImpl {
+    fn convert(&self, level: f32) -> Attributes {
+        if self.attributes.is_empty() || !(0.0..1.0).contains(&level) {
+            Attributes::default()
+        } else {
+            let len = self.attributes.len() as f32;
+            let index = (level * len) as usize;
+
+            self.attributes[index]
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crossterm::style::Attribute;
+
+    #[test]
+    fn convert() {
+        let converter =
+            AttributeConverterImpl::new(vec![Attribute::Dim.into(), Attribute::Bold.into()]);
+
+        assert_eq!(Attributes::from(Attribute::Dim), converter.convert(0.0));
+        assert_eq!(Attributes::from(Attribute::Dim), converter.convert(0.4));
+        assert_eq!(Attributes::from(Attribute::Bold), converter.convert(0.6));
+    }
+
+    #[test]
+    fn convert_outside_is_empty() {
+        let converter = AttributeConverterImpl::new(vec![Attribute::Bold.into()]);
+
+        assert!(converter.convert(-0.1).is_empty());
+        assert!(converter.convert(1.0).is_empty());
+    }
+
+    #[test]
+    fn convert_no_attributes() {
+        let converter = AttributeConverterImpl::new(Vec::new());
+
+        assert!(converter.convert(0.5).is_empty());
+    }
+}
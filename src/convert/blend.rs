@@ -0,0 +1,252 @@
+use crossterm::style::Color;
+
+/// The named colors in the order of their 256 color index.
+pub(crate) const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+/// The interpolation between neighbouring colors of a pallet.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug, Default)]
+pub enum Interpolation {
+    /// Use the nearest color.
+    #[default]
+    None,
+    /// Mix the colors in sRGB space.
+    Srgb,
+    /// Mix the colors in OKLab space.
+    Oklab,
+}
+
+impl Interpolation {
+    /// Mixes two colors, where zero returns the first color and one the second color.
+    /// Colors without a RGB representation are not mixed.
+    pub fn mix(&self, a: Color, b: Color, t: f32) -> Color {
+        let (a, b) = match (self, rgb(a), rgb(b)) {
+            (Interpolation::None, _, _) | (_, None, _) | (_, _, None) => return a,
+            (_, Some(a), Some(b)) => (a, b),
+        };
+        match self {
+            Interpolation::Srgb => from_srgb(lerp(to_srgb(a), to_srgb(b), t)),
+            _ => from_oklab(lerp(to_oklab(a), to_oklab(b), t)),
+        }
+    }
+}
+
+/// Returns the RGB representation of a color.
+///
+/// Named colors use the default xterm pallet.
+pub fn rgb(color: Color) -> Option<(u8, u8, u8)> {
+    match color {
+        Color::Reset => None,
+        Color::Black => Some((0, 0, 0)),
+        Color::DarkRed => Some((205, 0, 0)),
+        Color::DarkGreen => Some((0, 205, 0)),
+        Color::DarkYellow => Some((205, 205, 0)),
+        Color::DarkBlue => Some((0, 0, 238)),
+        Color::DarkMagenta => Some((205, 0, 205)),
+        Color::DarkCyan => Some((0, 205, 205)),
+        Color::Grey => Some((229, 229, 229)),
+        Color::DarkGrey => Some((127, 127, 127)),
+        Color::Red => Some((255, 0, 0)),
+        Color::Green => Some((0, 255, 0)),
+        Color::Yellow => Some((255, 255, 0)),
+        Color::Blue => Some((92, 92, 255)),
+        Color::Magenta => Some((255, 0, 255)),
+        Color::Cyan => Some((0, 255, 255)),
+        Color::White => Some((255, 255, 255)),
+        Color::Rgb { r, g, b } => Some((r, g, b)),
+        Color::AnsiValue(value) => Some(ansi_rgb(value)),
+    }
+}
+
+/// Returns the RGB representation of a 256 color index.
+fn ansi_rgb(value: u8) -> (u8, u8, u8) {
+    let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+
+    match value {
+        0..=15 => rgb(NAMED[value as usize]).unwrap(),
+        16..=231 => {
+            let value = value - 16;
+            (level(value / 36), level(value / 6 % 6), level(value % 6))
+        }
+        _ => {
+            let gray = 8 + (value - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Linearly interpolates between two triples.
+fn lerp(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+    ]
+}
+
+fn to_srgb((r, g, b): (u8, u8, u8)) -> [f32; 3] {
+    [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0]
+}
+
+fn from_srgb([r, g, b]: [f32; 3]) -> Color {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    Color::Rgb {
+        r: channel(r),
+        g: channel(g),
+        b: channel(b),
+    }
+}
+
+fn to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn to_oklab(color: (u8, u8, u8)) -> [f32; 3] {
+    let [r, g, b] = to_srgb(color).map(to_linear);
+
+    let l = (0.4122215 * r + 0.5363325 * g + 0.0514460 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.107397 * b).cbrt();
+    let s = (0.0883025 * r + 0.2817188 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.2104543 * l + 0.7936178 * m - 0.0040720 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.0259040 * l + 0.7827718 * m - 0.8086758 * s,
+    ]
+}
+
+fn from_oklab([l, a, b]: [f32; 3]) -> Color {
+    let l_ = (l + 0.3963378 * a + 0.2158038 * b).powi(3);
+    let m_ = (l - 0.1055613 * a - 0.0638542 * b).powi(3);
+    let s_ = (l - 0.0894842 * a - 1.2914855 * b).powi(3);
+
+    from_srgb(
+        [
+            4.0767417 * l_ - 3.3077116 * m_ + 0.2309699 * s_,
+            -1.268438 * l_ + 2.6097574 * m_ - 0.3413194 * s_,
+            -0.0041961 * l_ - 0.7034186 * m_ + 1.7076147 * s_,
+        ]
+        .map(from_linear),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn mix_none_keeps_first() {
+        assert_eq!(
+            Color::Red,
+            Interpolation::None.mix(Color::Red, Color::Blue, 0.7)
+        );
+    }
+
+    #[test]
+    fn mix_srgb() {
+        let a = Color::Rgb {
+            r: 0,
+            g: 100,
+            b: 200,
+        };
+        let b = Color::Rgb {
+            r: 100,
+            g: 200,
+            b: 0,
+        };
+
+        assert_eq!(
+            Color::Rgb {
+                r: 50,
+                g: 150,
+                b: 100
+            },
+            Interpolation::Srgb.mix(a, b, 0.5)
+        );
+    }
+
+    #[test]
+    fn mix_srgb_named() {
+        assert_eq!(
+            Color::Rgb {
+                r: 128,
+                g: 128,
+                b: 128
+            },
+            Interpolation::Srgb.mix(Color::Black, Color::White, 0.5)
+        );
+    }
+
+    #[test]
+    fn mix_oklab_ends() {
+        let a = Color::Rgb { r: 255, g: 0, b: 0 };
+        let b = Color::Rgb { r: 0, g: 0, b: 255 };
+
+        assert_eq!(a, Interpolation::Oklab.mix(a, b, 0.0));
+        assert_eq!(b, Interpolation::Oklab.mix(a, b, 1.0));
+    }
+
+    #[test]
+    fn mix_oklab_gray() {
+        let a = Color::Rgb { r: 0, g: 0, b: 0 };
+        let b = Color::Rgb {
+            r: 255,
+            g: 255,
+            b: 255,
+        };
+
+        match Interpolation::Oklab.mix(a, b, 0.5) {
+            Color::Rgb { r, g, b } => {
+                assert_eq!(r, g);
+                assert_eq!(g, b);
+                assert!(r > 90 && r < 110);
+            }
+            color => panic!("Unexpected color: {:?}", color),
+        }
+    }
+
+    #[test]
+    fn mix_reset_not_mixed() {
+        assert_eq!(
+            Color::Reset,
+            Interpolation::Srgb.mix(Color::Reset, Color::White, 0.5)
+        );
+    }
+
+    #[test]
+    fn rgb_ansi_value() {
+        assert_eq!(Some((205, 0, 0)), rgb(Color::AnsiValue(1)));
+        assert_eq!(Some((255, 95, 0)), rgb(Color::AnsiValue(202)));
+        assert_eq!(Some((8, 8, 8)), rgb(Color::AnsiValue(232)));
+        assert_eq!(Some((238, 238, 238)), rgb(Color::AnsiValue(255)));
+    }
+}
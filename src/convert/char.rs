@@ -9,10 +9,17 @@ pub enum CharSample {
     Clear,
 }
 
+/// The bits of the braille dots in column-major order.
+const BRAILLE_DOTS: [u32; 8] = [0x01, 0x02, 0x04, 0x40, 0x08, 0x10, 0x20, 0x80];
+
 /// A trait to convert a sample to a [CharSample].
 #[cfg_attr(test, mockall::automock)]
 pub trait CharConverter {
     fn convert(&self, level: f32) -> CharSample;
+
+    /// Converts the samples of a 2x4 grid in column-major order to a braille [CharSample].
+    /// A dot is set if the sample would be drawn.
+    fn braille(&self, levels: &[f32; 8]) -> CharSample;
 }
 
 /// The implementation of [CharConverter].
@@ -42,6 +49,23 @@ impl CharConverter for CharConverterImpl {
             CharSample::Keep
         }
     }
+
+    fn braille(&self, levels: &[f32; 8]) -> CharSample {
+        let samples = levels.map(|level| self.convert(level));
+        let dots = samples
+            .iter()
+            .zip(BRAILLE_DOTS)
+            .filter(|(sample, _)| matches!(sample, CharSample::Draw(_)))
+            .fold(0, |dots, (_, bit)| dots | bit);
+
+        if dots != 0 {
+            CharSample::Draw(char::from_u32(0x2800 + dots).unwrap())
+        } else if samples.iter().all(|sample| *sample == CharSample::Keep) {
+            CharSample::Keep
+        } else {
+            CharSample::Clear
+        }
+    }
 }
 
 #[cfg(test)]
@@ -71,4 +95,34 @@ mod test {
         assert_eq!(CharSample::Keep, converter.convert(1.0));
         assert_eq!(CharSample::Keep, converter.convert(1.5));
     }
+
+    #[test]
+    fn braille_dots() {
+        let converter = CharConverterImpl::new("#".to_string());
+        let levels = [0.5, 1.0, -1.0, 0.5, 1.0, 0.0, 1.0, 0.9];
+
+        assert_eq!(CharSample::Draw('\u{28D1}'), converter.braille(&levels));
+    }
+
+    #[test]
+    fn braille_all_dots() {
+        let converter = CharConverterImpl::new("#".to_string());
+
+        assert_eq!(CharSample::Draw('\u{28FF}'), converter.braille(&[0.5; 8]));
+    }
+
+    #[test]
+    fn braille_keep() {
+        let converter = CharConverterImpl::new("#".to_string());
+
+        assert_eq!(CharSample::Keep, converter.braille(&[1.0; 8]));
+    }
+
+    #[test]
+    fn braille_clear() {
+        let converter = CharConverterImpl::new("#".to_string());
+        let levels = [1.0, 1.0, 1.0, 1.0, -0.5, 1.0, 1.0, 1.0];
+
+        assert_eq!(CharSample::Clear, converter.braille(&levels));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::convert::Interpolation;
 use crossterm::style::Color;
 
 /// A trait to convert a sample to a [Color].
@@ -9,21 +10,35 @@ pub trait ColorConverter {
 /// The implementation of [ColorConverter].
 pub struct ColorConverterImpl {
     colors: Vec<Color>,
+    cyclic: bool,
+    interpolation: Interpolation,
 }
 
 impl ColorConverterImpl {
-    /// The colors used for mapping.
-    pub fn new(colors: Vec<Color>) -> Self {
-        Self { colors }
+    /// The colors used for mapping, whether the last color is mixed
+    /// with the first one and how to mix them.
+    pub fn new(colors: Vec<Color>, cyclic: bool, interpolation: Interpolation) -> Self {
+        Self {
+            colors,
+            cyclic,
+            interpolation,
+        }
     }
 }
 
 impl ColorConverter for ColorConverterImpl {
     fn convert(&self, level: f32) -> Color {
         let len = self.colors.len() as f32;
-        let index = (level * len).rem_euclid(len) as usize;
-
-        self.colors[index]
+        let pos = (level * len).rem_euclid(len);
+        let index = pos as usize;
+        let next = if self.cyclic {
+            (index + 1) % self.colors.len()
+        } else {
+            (index + 1).min(self.colors.len() - 1)
+        };
+
+        self.interpolation
+            .mix(self.colors[index], self.colors[next], pos.fract())
     }
 }
 
@@ -34,36 +49,94 @@ mod test {
 
     #[test]
     fn convert_negative_index() {
-        let converter = ColorConverterImpl::new(vec![Red, Green, Blue]);
+        let converter = ColorConverterImpl::new(vec![Red, Green, Blue], false, Interpolation::None);
 
         assert_eq!(Blue, converter.convert(-0.2));
     }
 
     #[test]
     fn convert_index_zero() {
-        let converter = ColorConverterImpl::new(vec![Red, Green, Blue]);
+        let converter = ColorConverterImpl::new(vec![Red, Green, Blue], false, Interpolation::None);
 
         assert_eq!(Red, converter.convert(0.0));
     }
 
     #[test]
     fn convert() {
-        let converter = ColorConverterImpl::new(vec![Red, Green, Blue]);
+        let converter = ColorConverterImpl::new(vec![Red, Green, Blue], false, Interpolation::None);
 
         assert_eq!(Green, converter.convert(0.5));
     }
 
     #[test]
     fn convert_index_one() {
-        let converter = ColorConverterImpl::new(vec![Red, Green, Blue]);
+        let converter = ColorConverterImpl::new(vec![Red, Green, Blue], false, Interpolation::None);
 
         assert_eq!(Red, converter.convert(1.0));
     }
 
     #[test]
     fn convert_index_above_one() {
-        let converter = ColorConverterImpl::new(vec![Red, Green, Blue]);
+        let converter = ColorConverterImpl::new(vec![Red, Green, Blue], false, Interpolation::None);
 
         assert_eq!(Green, converter.convert(1.5));
     }
+
+    #[test]
+    fn convert_interpolated() {
+        let colors = vec![
+            Rgb { r: 0, g: 0, b: 0 },
+            Rgb {
+                r: 200,
+                g: 100,
+                b: 0,
+            },
+        ];
+        let converter = ColorConverterImpl::new(colors, false, Interpolation::Srgb);
+
+        assert_eq!(
+            Rgb {
+                r: 100,
+                g: 50,
+                b: 0
+            },
+            converter.convert(0.25)
+        );
+    }
+
+    #[test]
+    fn convert_interpolated_wraps_around() {
+        let colors = vec![
+            Rgb { r: 0, g: 0, b: 0 },
+            Rgb {
+                r: 200,
+                g: 100,
+                b: 0,
+            },
+        ];
+        let converter = ColorConverterImpl::new(colors, true, Interpolation::Srgb);
+
+        assert_eq!(
+            Rgb {
+                r: 100,
+                g: 50,
+                b: 0
+            },
+            converter.convert(0.75)
+        );
+    }
+
+    #[test]
+    fn convert_interpolated_clamps_to_last() {
+        let last = Rgb {
+            r: 200,
+            g: 100,
+            b: 0,
+        };
+        let colors = vec![Rgb { r: 0, g: 0, b: 0 }, last];
+        let converter = ColorConverterImpl::new(colors, false, Interpolation::Srgb);
+
+        assert_eq!(last, converter.convert(0.75));
+        assert_eq!(last, converter.convert(0.99));
+    }
 }
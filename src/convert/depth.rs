@@ -0,0 +1,201 @@
+use crate::convert::{rgb, ColorConverter, NAMED};
+use crossterm::style::Color;
+
+/// The colors supported by a terminal.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug)]
+pub enum ColorDepth {
+    /// No colors at all.
+    None,
+    /// The 16 named colors.
+    Ansi16,
+    /// The 256 indexed colors.
+    Ansi256,
+    /// Any RGB color.
+    Truecolor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth from the environment variables
+    /// `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> ColorDepth {
+        if var("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return ColorDepth::None;
+        }
+        if let Some("truecolor" | "24bit") = var("COLORTERM").as_deref() {
+            return ColorDepth::Truecolor;
+        }
+        match var("TERM") {
+            Some(term) if term == "dumb" => ColorDepth::None,
+            Some(term) if term.ends_with("-direct") => ColorDepth::Truecolor,
+            Some(term) if term.contains("256color") => ColorDepth::Ansi256,
+            _ => ColorDepth::Ansi16,
+        }
+    }
+
+    /// Maps a color to the closest color with this depth.
+    pub fn downsample(&self, color: Color) -> Color {
+        match (self, color) {
+            (ColorDepth::None, _) => Color::Reset,
+            (ColorDepth::Truecolor, _) => color,
+            (ColorDepth::Ansi256, Color::Rgb { r, g, b }) => Color::AnsiValue(ansi256((r, g, b))),
+            (ColorDepth::Ansi16, Color::Rgb { .. } | Color::AnsiValue(_)) => ansi16(color),
+            _ => color,
+        }
+    }
+}
+
+/// A [ColorConverter] which reduces the colors of the child to a [ColorDepth].
+#[derive(derive_more::Constructor)]
+pub struct ColorDepthConverter<T> {
+    child: T,
+    depth: ColorDepth,
+}
+
+impl<T: ColorConverter> ColorConverter for ColorDepthConverter<T> {
+    fn convert(&self, level: f32) -> Color {
+        self.depth.downsample(self.child.convert(level))
+    }
+}
+
+/// Returns the squared distance of two colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// Returns the closest of the 256 indexed colors.
+fn ansi256(color: (u8, u8, u8)) -> u8 {
+    let level = |c: u8| match c {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (c - 35) / 40,
+    };
+    let cube = 16 + 36 * level(color.0) + 6 * level(color.1) + level(color.2);
+    let average = (color.0 as u16 + color.1 as u16 + color.2 as u16) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    [cube, gray]
+        .into_iter()
+        .min_by_key(|index| distance(color, rgb(Color::AnsiValue(*index)).unwrap()))
+        .unwrap()
+}
+
+/// Returns the closest of the 16 named colors.
+fn ansi16(color: Color) -> Color {
+    let color = rgb(color).unwrap();
+
+    NAMED
+        .into_iter()
+        .min_by_key(|named| distance(color, rgb(*named).unwrap()))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::convert::MockColorConverter;
+    use crate::test_util::env;
+    use mockall::predicate::eq;
+
+    #[test]
+    fn detect_no_color() {
+        let var = env(&[("NO_COLOR", "1"), ("COLORTERM", "truecolor")]);
+
+        assert_eq!(ColorDepth::None, ColorDepth::detect(var));
+    }
+
+    #[test]
+    fn detect_empty_no_color_ignored() {
+        let var = env(&[("NO_COLOR", ""), ("COLORTERM", "truecolor")]);
+
+        assert_eq!(ColorDepth::Truecolor, ColorDepth::detect(var));
+    }
+
+    #[test]
+    fn detect_colorterm() {
+        let var = env(&[("COLORTERM", "24bit"), ("TERM", "xterm")]);
+
+        assert_eq!(ColorDepth::Truecolor, ColorDepth::detect(var));
+    }
+
+    #[test]
+    fn detect_term() {
+        let detect = |term| ColorDepth::detect(env(&[("TERM", term)]));
+
+        assert_eq!(ColorDepth::None, detect("dumb"));
+        assert_eq!(ColorDepth::Ansi16, detect("linux"));
+        assert_eq!(ColorDepth::Ansi256, detect("xterm-256color"));
+        assert_eq!(ColorDepth::Truecolor, detect("xterm-direct"));
+    }
+
+    #[test]
+    fn detect_nothing_set() {
+        assert_eq!(ColorDepth::Ansi16, ColorDepth::detect(env(&[])));
+    }
+
+    #[test]
+    fn downsample_none() {
+        assert_eq!(Color::Reset, ColorDepth::None.downsample(Color::Red));
+    }
+
+    #[test]
+    fn downsample_truecolor_untouched() {
+        let color = Color::Rgb { r: 1, g: 2, b: 3 };
+
+        assert_eq!(color, ColorDepth::Truecolor.downsample(color));
+    }
+
+    #[test]
+    fn downsample_ansi256() {
+        let depth = ColorDepth::Ansi256;
+
+        assert_eq!(
+            Color::AnsiValue(202),
+            depth.downsample(Color::Rgb {
+                r: 250,
+                g: 90,
+                b: 10
+            })
+        );
+        assert_eq!(
+            Color::AnsiValue(244),
+            depth.downsample(Color::Rgb {
+                r: 128,
+                g: 128,
+                b: 130
+            })
+        );
+        assert_eq!(Color::AnsiValue(7), depth.downsample(Color::AnsiValue(7)));
+        assert_eq!(Color::Red, depth.downsample(Color::Red));
+    }
+
+    #[test]
+    fn downsample_ansi16() {
+        let depth = ColorDepth::Ansi16;
+
+        assert_eq!(
+            Color::DarkRed,
+            depth.downsample(Color::Rgb {
+                r: 190,
+                g: 10,
+                b: 0
+            })
+        );
+        assert_eq!(Color::Cyan, depth.downsample(Color::AnsiValue(51)));
+        assert_eq!(Color::Blue, depth.downsample(Color::Blue));
+    }
+
+    #[test]
+    fn converter() {
+        let mut child = MockColorConverter::new();
+        child
+            .expect_convert()
+            .with(eq(0.3))
+            .return_const(Color::Rgb { r: 0, g: 0, b: 0 });
+
+        let converter = ColorDepthConverter::new(child, ColorDepth::Ansi16);
+
+        assert_eq!(Color::Black, converter.convert(0.3));
+    }
+}
@@ -1,44 +1,78 @@
 //! Contains structs for converting samples to concrete types.
 
+mod attribute;
+mod blend;
 mod char;
 mod color;
+mod depth;
 
+pub use crate::convert::attribute::*;
+pub use crate::convert::blend::*;
 pub use crate::convert::char::*;
 pub use crate::convert::color::*;
+pub use crate::convert::depth::*;
 
-use crossterm::style::Color;
+use crossterm::style::{Attributes, Color};
 
 /// A trait to convert samples to concrete types.
 #[cfg_attr(test, mockall::automock)]
 pub trait Converter {
     /// Converts a sample to a [CharSample].
     fn char(&self, level: f32) -> CharSample;
+    /// Converts the samples of a 2x4 grid to a braille [CharSample].
+    fn braille(&self, levels: &[f32; 8]) -> CharSample;
     /// Converts a sample to a [Color].
     fn color(&self, level: f32) -> Color;
+    /// Converts a sample to a background [Color].
+    fn background(&self, level: f32) -> Color;
+    /// Converts a char sample to [Attributes].
+    fn attributes(&self, level: f32) -> Attributes;
 }
 
 /// The implementation of [Converter].
 #[derive(derive_more::Constructor)]
-pub struct ConverterImpl<T1, T2> {
+pub struct ConverterImpl<T1, T2, T3, T4> {
     char: T1,
     color: T2,
+    background: T3,
+    attributes: T4,
 }
 
-impl<T1: CharConverter, T2: ColorConverter> Converter for ConverterImpl<T1, T2> {
+impl<T1, T2, T3, T4> Converter for ConverterImpl<T1, T2, T3, T4>
+where
+    T1: CharConverter,
+    T2: ColorConverter,
+    T3: ColorConverter,
+    T4: AttributeConverter,
+{
     fn char(&self, level: f32) -> CharSample {
         self.char.convert(level)
     }
 
+    fn braille(&self, levels: &[f32; 8]) -> CharSample {
+        self.char.braille(levels)
+    }
+
     fn color(&self, level: f32) -> Color {
         self.color.convert(level)
     }
+
+    fn background(&self, level: f32) -> Color {
+        self.background.convert(level)
+    }
+
+    fn attributes(&self, level: f32) -> Attributes {
+        self.attributes.convert(level)
+    }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::convert::MockAttributeConverter;
     use crate::convert::MockCharConverter;
     use crate::convert::MockColorConverter;
+    use crossterm::style::Attribute;
     use mockall::predicate::*;
 
     #[test]
@@ -50,11 +84,35 @@ mod test {
             .with(eq(4.0))
             .return_const(CharSample::Draw('M'));
 
-        let converter = ConverterImpl::new(char, color);
+        let converter = ConverterImpl::new(
+            char,
+            color,
+            MockColorConverter::new(),
+            MockAttributeConverter::new(),
+        );
 
         assert_eq!(CharSample::Draw('M'), converter.char(4.0));
     }
 
+    #[test]
+    fn braille() {
+        let mut char = MockCharConverter::new();
+        let color = MockColorConverter::new();
+
+        char.expect_braille()
+            .with(eq([0.5; 8]))
+            .return_const(CharSample::Draw('\u{28FF}'));
+
+        let converter = ConverterImpl::new(
+            char,
+            color,
+            MockColorConverter::new(),
+            MockAttributeConverter::new(),
+        );
+
+        assert_eq!(CharSample::Draw('\u{28FF}'), converter.braille(&[0.5; 8]));
+    }
+
     #[test]
     fn color() {
         let char = MockCharConverter::new();
@@ -65,8 +123,51 @@ mod test {
             .with(eq(2.0))
             .return_const(Color::Yellow);
 
-        let converter = ConverterImpl::new(char, color);
+        let converter = ConverterImpl::new(
+            char,
+            color,
+            MockColorConverter::new(),
+            MockAttributeConverter::new(),
+        );
 
         assert_eq!(Color::Yellow, converter.color(2.0));
     }
+
+    #[test]
+    fn background() {
+        let char = MockCharConverter::new();
+        let color = MockColorConverter::new();
+        let mut background = MockColorConverter::new();
+
+        background
+            .expect_convert()
+            .with(eq(0.7))
+            .return_const(Color::Magenta);
+
+        let converter = ConverterImpl::new(char, color, background, MockAttributeConverter::new());
+
+        assert_eq!(Color::Magenta, converter.background(0.7));
+    }
+
+    #[test]
+    fn attributes() {
+        let mut attributes = MockAttributeConverter::new();
+
+        attributes
+            .expect_convert()
+            .with(eq(0.1))
+            .return_const(Attributes::from(Attribute::Italic));
+
+        let converter = ConverterImpl::new(
+            MockCharConverter::new(),
+            MockColorConverter::new(),
+            MockColorConverter::new(),
+            attributes,
+        );
+
+        assert_eq!(
+            Attributes::from(Attribute::Italic),
+            converter.attributes(0.1)
+        );
+    }
 }
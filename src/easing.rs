@@ -0,0 +1,190 @@
+use std::f32::consts::PI;
+use std::str::FromStr;
+
+/// An easing curve for the animation progress.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    EaseOut,
+    /// Accelerates until the middle, then decelerates.
+    EaseInOut,
+    /// Like [Easing::EaseInOut], but with a steeper middle.
+    Cubic,
+    /// Overshoots the end and settles with a spring motion.
+    Elastic,
+    /// Bounces against the end.
+    Bounce,
+    /// Jumps in `n` discrete steps.
+    Steps(u16),
+}
+
+impl Easing {
+    /// Maps the linear progress to the eased progress.
+    /// Both start with zero and end with one.
+    pub fn apply(&self, t: f32) -> f32 {
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(2) / 2.0
+                }
+            }
+            Easing::Cubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (2.0 - 2.0 * t).powi(3) / 2.0
+                }
+            }
+            Easing::Elastic => {
+                if t <= 0.0 || t >= 1.0 {
+                    t
+                } else {
+                    2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * 2.0 * PI / 3.0).sin() + 1.0
+                }
+            }
+            Easing::Bounce => bounce(t),
+            Easing::Steps(n) => (t * n as f32).floor() / n as f32,
+        }
+    }
+}
+
+impl FromStr for Easing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(Easing::Linear),
+            "ease-in" => Ok(Easing::EaseIn),
+            "ease-out" => Ok(Easing::EaseOut),
+            "ease-in-out" => Ok(Easing::EaseInOut),
+            "cubic" => Ok(Easing::Cubic),
+            "elastic" => Ok(Easing::Elastic),
+            "bounce" => Ok(Easing::Bounce),
+            _ => s
+                .strip_prefix("steps(")
+                .and_then(|s| s.strip_suffix(')'))
+                .and_then(|n| n.parse().ok())
+                .filter(|n| *n > 0)
+                .map(Easing::Steps)
+                .ok_or_else(|| format!("Invalid easing: {}", s)),
+        }
+    }
+}
+
+/// Returns the progress of a ball bouncing against the end.
+fn bounce(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::*;
+
+    #[test]
+    fn linear() {
+        assert_abs_diff_eq!(0.0, Easing::Linear.apply(0.0));
+        assert_abs_diff_eq!(0.3, Easing::Linear.apply(0.3));
+        assert_abs_diff_eq!(1.0, Easing::Linear.apply(1.0));
+    }
+
+    #[test]
+    fn ease_in() {
+        assert_abs_diff_eq!(0.0, Easing::EaseIn.apply(0.0));
+        assert_abs_diff_eq!(0.25, Easing::EaseIn.apply(0.5));
+        assert_abs_diff_eq!(1.0, Easing::EaseIn.apply(1.0));
+    }
+
+    #[test]
+    fn ease_out() {
+        assert_abs_diff_eq!(0.0, Easing::EaseOut.apply(0.0));
+        assert_abs_diff_eq!(0.75, Easing::EaseOut.apply(0.5));
+        assert_abs_diff_eq!(1.0, Easing::EaseOut.apply(1.0));
+    }
+
+    #[test]
+    fn ease_in_out() {
+        assert_abs_diff_eq!(0.0, Easing::EaseInOut.apply(0.0));
+        assert_abs_diff_eq!(0.125, Easing::EaseInOut.apply(0.25));
+        assert_abs_diff_eq!(0.5, Easing::EaseInOut.apply(0.5));
+        assert_abs_diff_eq!(0.875, Easing::EaseInOut.apply(0.75));
+        assert_abs_diff_eq!(1.0, Easing::EaseInOut.apply(1.0));
+    }
+
+    #[test]
+    fn cubic() {
+        assert_abs_diff_eq!(0.0, Easing::Cubic.apply(0.0));
+        assert_abs_diff_eq!(0.0625, Easing::Cubic.apply(0.25));
+        assert_abs_diff_eq!(0.5, Easing::Cubic.apply(0.5));
+        assert_abs_diff_eq!(0.9375, Easing::Cubic.apply(0.75));
+        assert_abs_diff_eq!(1.0, Easing::Cubic.apply(1.0));
+    }
+
+    #[test]
+    fn elastic() {
+        assert_abs_diff_eq!(0.0, Easing::Elastic.apply(0.0));
+        assert!(Easing::Elastic.apply(0.1) > 1.0);
+        assert_abs_diff_eq!(1.0, Easing::Elastic.apply(0.9), epsilon = 0.01);
+        assert_abs_diff_eq!(1.0, Easing::Elastic.apply(1.0));
+    }
+
+    #[test]
+    fn bounce() {
+        assert_abs_diff_eq!(0.0, Easing::Bounce.apply(0.0));
+        assert_abs_diff_eq!(0.75, Easing::Bounce.apply(1.5 / 2.75));
+        assert_abs_diff_eq!(1.0, Easing::Bounce.apply(1.0 / 2.75));
+        assert_abs_diff_eq!(1.0, Easing::Bounce.apply(1.0), epsilon = 0.001);
+    }
+
+    #[test]
+    fn steps() {
+        assert_abs_diff_eq!(0.0, Easing::Steps(4).apply(0.2));
+        assert_abs_diff_eq!(0.25, Easing::Steps(4).apply(0.3));
+        assert_abs_diff_eq!(0.75, Easing::Steps(4).apply(0.99));
+        assert_abs_diff_eq!(1.0, Easing::Steps(4).apply(1.0));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(Easing::Linear), "linear".parse());
+        assert_eq!(Ok(Easing::EaseIn), "ease-in".parse());
+        assert_eq!(Ok(Easing::EaseOut), "ease-out".parse());
+        assert_eq!(Ok(Easing::EaseInOut), "ease-in-out".parse());
+        assert_eq!(Ok(Easing::Cubic), "cubic".parse());
+        assert_eq!(Ok(Easing::Elastic), "elastic".parse());
+        assert_eq!(Ok(Easing::Bounce), "bounce".parse());
+        assert_eq!(Ok(Easing::Steps(12)), "steps(12)".parse());
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        assert!("quadratic".parse::<Easing>().is_err());
+        assert!("steps(0)".parse::<Easing>().is_err());
+        assert!("steps(x)".parse::<Easing>().is_err());
+        assert!("steps(3".parse::<Easing>().is_err());
+    }
+}
@@ -21,6 +21,12 @@ impl From<&str> for Error {
     }
 }
 
+impl From<String> for Error {
+    fn from(msg: String) -> Self {
+        Error(msg)
+    }
+}
+
 impl From<ctrlc::Error> for Error {
     fn from(err: ctrlc::Error) -> Self {
         Error(err.to_string())
@@ -33,7 +39,7 @@ mod test {
 
     #[test]
     fn from_std_io_error() {
-        let io_err = std::io::Error::new(std::io::ErrorKind::Other, "123");
+        let io_err = std::io::Error::other("123");
         let msg = io_err.to_string();
         let err: Error = io_err.into();
 
@@ -46,4 +52,11 @@ mod test {
 
         assert_eq!("123", format!("{:?}", err));
     }
+
+    #[test]
+    fn from_string() {
+        let err: Error = "123".to_string().into();
+
+        assert_eq!("123", format!("{:?}", err));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::Easing;
 use crate::Error;
 use crate::Renderer;
 use cancellation::CancellationToken;
@@ -34,6 +35,7 @@ pub struct Executor<T> {
     clock: T,
     duration: Duration,
     delay: Duration,
+    easing: Easing,
 }
 
 impl<T: Clock> Executor<T> {
@@ -45,7 +47,7 @@ impl<T: Clock> Executor<T> {
         while !token.is_canceled() && tick.duration_since(start) < self.duration {
             let step = tick.duration_since(start).as_secs_f32() / self.duration.as_secs_f32();
 
-            renderer.render(step)?;
+            renderer.render(self.easing.apply(step))?;
             tick = self.delay(tick);
         }
         Ok(())
@@ -93,7 +95,12 @@ mod test {
             .return_const(begin + Duration::from_secs(20))
             .in_sequence(clock_seq);
 
-        let timer = Executor::new(clock, Duration::from_secs(20), Duration::from_secs(10));
+        let timer = Executor::new(
+            clock,
+            Duration::from_secs(20),
+            Duration::from_secs(10),
+            Easing::Linear,
+        );
 
         let mut renderer = MockRenderer::new();
         let renderer_seq = &mut Sequence::new();
@@ -114,6 +121,54 @@ mod test {
         timer.run(renderer, CancellationToken::none()).unwrap();
     }
 
+    #[test]
+    fn run_steps_eased() {
+        let mut clock = MockClock::new();
+        let clock_seq = &mut Sequence::new();
+        let begin = Instant::now();
+
+        clock
+            .expect_now()
+            .once()
+            .return_const(begin)
+            .in_sequence(clock_seq);
+        clock
+            .expect_now()
+            .times(2)
+            .return_const(begin + Duration::from_secs(10))
+            .in_sequence(clock_seq);
+        clock
+            .expect_now()
+            .times(2)
+            .return_const(begin + Duration::from_secs(20))
+            .in_sequence(clock_seq);
+
+        let timer = Executor::new(
+            clock,
+            Duration::from_secs(20),
+            Duration::from_secs(10),
+            Easing::EaseIn,
+        );
+
+        let mut renderer = MockRenderer::new();
+        let renderer_seq = &mut Sequence::new();
+
+        renderer
+            .expect_render()
+            .with(eq(0.0))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(renderer_seq);
+        renderer
+            .expect_render()
+            .with(eq(0.25))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(renderer_seq);
+
+        timer.run(renderer, CancellationToken::none()).unwrap();
+    }
+
     #[test]
     fn run_sleep_duration_correct() {
         let mut clock = MockClock::new();
@@ -142,7 +197,12 @@ mod test {
             .return_const(begin + Duration::from_secs(10))
             .in_sequence(clock_seq);
 
-        let timer = Executor::new(clock, Duration::from_secs(10), Duration::from_secs(10));
+        let timer = Executor::new(
+            clock,
+            Duration::from_secs(10),
+            Duration::from_secs(10),
+            Easing::Linear,
+        );
 
         let mut renderer = MockRenderer::new();
         renderer.expect_render().returning(|_| Ok(()));
@@ -167,7 +227,12 @@ mod test {
             .return_const(begin + Duration::from_secs(12))
             .in_sequence(clock_seq);
 
-        let timer = Executor::new(clock, Duration::from_secs(10), Duration::from_secs(10));
+        let timer = Executor::new(
+            clock,
+            Duration::from_secs(10),
+            Duration::from_secs(10),
+            Easing::Linear,
+        );
 
         let mut renderer = MockRenderer::new();
         renderer.expect_render().returning(|_| Ok(()));
@@ -189,7 +254,12 @@ mod test {
 
             Ok(())
         });
-        let timer = Executor::new(clock, Duration::from_secs(10), Duration::from_secs(1));
+        let timer = Executor::new(
+            clock,
+            Duration::from_secs(10),
+            Duration::from_secs(1),
+            Easing::Linear,
+        );
 
         timer.run(renderer, &token).unwrap();
     }
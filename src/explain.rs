@@ -0,0 +1,138 @@
+/// The format of a resolved configuration.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug)]
+pub enum Explain {
+    /// A command line which can be copied into a shell.
+    Args,
+    /// A TOML table which can be copied into a config file.
+    Toml,
+}
+
+/// The value of a resolved argument.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+}
+
+impl Value {
+    /// Returns a raw argument as a boolean or an integer if it reads as one.
+    pub fn from_raw(value: &str) -> Self {
+        match value {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => match value.parse::<i64>() {
+                // Values like '01' would change as an integer.
+                Ok(int) if int.to_string() == value => Value::Int(int),
+                _ => Value::Str(value.to_string()),
+            },
+        }
+    }
+}
+
+impl Explain {
+    /// Formats a list of resolved arguments by their long name.
+    pub fn format(&self, args: &[(String, Value)]) -> String {
+        match self {
+            Explain::Args => {
+                let mut line = String::from("wipe");
+
+                for (name, value) in args {
+                    line += &match value {
+                        Value::Bool(value) => format!(" --{} {}", name, value),
+                        // Negative numbers would be parsed as a flag otherwise.
+                        Value::Int(value) if *value < 0 => format!(" --{}={}", name, value),
+                        Value::Int(value) => format!(" --{} {}", name, value),
+                        Value::Str(value) => format!(" --{} {}", name, quote_shell(value)),
+                    };
+                }
+                line
+            }
+            Explain::Toml => args
+                .iter()
+                .map(|(name, value)| match value {
+                    Value::Bool(value) => format!("{} = {}", name, value),
+                    Value::Int(value) => format!("{} = {}", name, value),
+                    Value::Str(value) => format!("{} = {}", name, quote_toml(value)),
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// Quotes a value for a POSIX shell if it contains special chars.
+fn quote_shell(value: &str) -> String {
+    let plain = |c: char| c.is_ascii_alphanumeric() || "_-.,:+/".contains(c);
+
+    if !value.is_empty() && value.chars().all(plain) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// Quotes a value as a TOML basic string.
+fn quote_toml(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', r"\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args() -> Vec<(String, Value)> {
+        vec![
+            ("char-pattern".to_string(), Value::Str("circle".to_string())),
+            ("char-invert".to_string(), Value::Bool(true)),
+            ("char-spin".to_string(), Value::Int(-90)),
+            ("colors".to_string(), Value::Str("#ff0000,red".to_string())),
+        ]
+    }
+
+    #[test]
+    fn format_args() {
+        assert_eq!(
+            "wipe --char-pattern circle --char-invert true --char-spin=-90 --colors '#ff0000,red'",
+            Explain::Args.format(&args())
+        );
+    }
+
+    #[test]
+    fn format_toml() {
+        let expected = "char-pattern = \"circle\"\n\
+                        char-invert = true\n\
+                        char-spin = -90\n\
+                        colors = \"#ff0000,red\"";
+
+        assert_eq!(expected, Explain::Toml.format(&args()));
+    }
+
+    #[test]
+    fn value_from_raw() {
+        assert_eq!(Value::Bool(true), Value::from_raw("true"));
+        assert_eq!(Value::Int(-90), Value::from_raw("-90"));
+        assert_eq!(Value::Str("01".to_string()), Value::from_raw("01"));
+        assert_eq!(
+            Value::Str("dim,bold".to_string()),
+            Value::from_raw("dim,bold")
+        );
+    }
+
+    #[test]
+    fn quote_shell_plain() {
+        assert_eq!("dark_red,ansi:202", quote_shell("dark_red,ansi:202"));
+    }
+
+    #[test]
+    fn quote_shell_special() {
+        assert_eq!("''", quote_shell(""));
+        assert_eq!("'a b'", quote_shell("a b"));
+        assert_eq!(r"'it'\''s'", quote_shell("it's"));
+    }
+
+    #[test]
+    fn quote_toml_special() {
+        assert_eq!(r#""a\"b\\c""#, quote_toml(r#"a"b\c"#));
+    }
+}
@@ -2,15 +2,24 @@ pub mod convert;
 pub mod pattern;
 pub mod transform;
 
+mod buffer;
+mod config;
+mod easing;
 mod error;
 mod exec;
+mod explain;
 mod printer;
 mod renderer;
 mod term;
+#[cfg(test)]
+mod test_util;
 mod vec;
 
+pub use config::*;
+pub use easing::*;
 pub use error::*;
 pub use exec::*;
+pub use explain::*;
 pub use printer::*;
 pub use renderer::*;
 pub use term::*;
@@ -20,12 +29,20 @@ use crate::convert::*;
 use crate::pattern::*;
 use crate::transform::*;
 use cancellation::CancellationTokenSource;
-use clap::builder::NonEmptyStringValueParser;
-use clap::{value_parser, Parser, ValueEnum};
-use crossterm::style::Color;
+use clap::builder::{NonEmptyStringValueParser, PossibleValue, TypedValueParser};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{
+    value_parser, Arg, ArgAction, ArgMatches, Command, CommandFactory, FromArgMatches, Parser,
+    ValueEnum,
+};
 use crossterm::style::Color::*;
+use crossterm::style::{Attribute, Attributes, Color};
 use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use std::ffi::{OsStr, OsString};
 use std::io::stdout;
+use std::path::PathBuf;
 use std::time::Duration;
 
 /// The command line arguments.
@@ -34,8 +51,18 @@ use std::time::Duration;
     author  = env!("CARGO_PKG_AUTHORS"),
     version = env!("CARGO_PKG_VERSION"),
     about   = env!("CARGO_PKG_DESCRIPTION"),
+    args_override_self = true,
 )]
 struct Args {
+    /// Choose the config file with the default arguments [default: $XDG_CONFIG_HOME/wipe/config.toml]
+    #[arg(long)]
+    config: Option<PathBuf>,
+    /// Choose a preset which fixes some of the arguments, like 'matrix', 'sunset' or 'minimal'
+    #[arg(long)]
+    preset: Option<String>,
+    /// Print all presets and their arguments
+    #[arg(long)]
+    list_presets: bool,
     /// Set the animation duration as milliseconds
     #[arg(
         long,
@@ -47,9 +74,71 @@ struct Args {
     /// Set the frames per second
     #[arg(long, default_value_t = 60, value_parser = value_parser!(u64).range(1..=480))]
     fps: u64,
+    /// Choose the easing curve of the animation
+    #[arg(
+        long,
+        default_value = "linear",
+        value_parser = value_parser!(Easing),
+        help = "Choose the easing curve [linear, ease-in, ease-out, ease-in-out, cubic, elastic, bounce, steps(n)]"
+    )]
+    easing: Easing,
     /// Choose the chars used to draw the pattern
     #[arg(long, default_value = ".:+#", value_parser = NonEmptyStringValueParser::new())]
     chars: String,
+    /// Choose how the cells are drawn
+    #[arg(long, value_enum, default_value_t = RenderMode::Char)]
+    render_mode: RenderMode,
+    /// Choose the text attributes drawn from the start to the end of the pattern, like 'dim,none,bold+italic'
+    #[arg(long, value_delimiter = ',', value_parser = parse_attributes)]
+    attributes: Vec<Attributes>,
+    /// Choose whether frames are drawn as synchronized updates to avoid tearing
+    #[arg(long, value_enum, default_value_t = SyncOutput::Auto)]
+    sync_output: SyncOutput,
+    /// Draw the animation on the alternate screen and keep the terminal content
+    #[arg(
+        long,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_value_t = false,
+        default_missing_value = "true"
+    )]
+    alternate_screen: bool,
+    /// Choose what is left on the terminal after the animation
+    #[arg(long, value_enum, default_value_t = Finish::Purge)]
+    finish: Finish,
+    /// Set the seed of all random choices to reproduce an animation
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Print the seed of the random choices after the animation
+    #[arg(
+        long,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_value_t = false,
+        default_missing_value = "true"
+    )]
+    print_seed: bool,
+    /// Print the resolved random choices instead of playing the animation
+    #[arg(
+        long,
+        visible_alias = "dry-run",
+        value_enum,
+        num_args = 0..=1,
+        default_missing_value = "args"
+    )]
+    explain: Option<Explain>,
+    /// Exclude patterns from the random choices, like 'wheel,dissolve'
+    #[arg(long, value_enum, value_delimiter = ',')]
+    exclude_pattern: Vec<PatternEnum>,
+    /// Choose the weights of the random patterns up to 65535, like 'circle=3,line=1' [default: 1]
+    #[arg(long, value_delimiter = ',', value_parser = parse_pattern_weight)]
+    pattern_weights: Vec<(PatternEnum, u16)>,
+    /// Choose the range of the random segment counts, like '1-4'
+    #[arg(long, default_value = "1-4", value_parser = parse_range)]
+    segments_range: Range,
+    /// Choose the range of the random shrink factors, like '1-4'
+    #[arg(long, default_value = "1-4", value_parser = parse_range)]
+    shrink_range: Range,
     /// Choose the pattern
     #[arg(long, value_enum)]
     char_pattern: Option<PatternEnum>,
@@ -65,9 +154,48 @@ struct Args {
     /// Choose the factor by which to shrink the pattern [default: 1-4]
     #[arg(long, value_parser = value_parser!(u8).range(1..255))]
     char_shrink: Option<u8>,
-    /// Choose the colors used for the pattern
+    /// Choose the arm count of the spiral pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    char_spiral_arms: Option<u8>,
+    /// Choose the turn count of the spiral pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    char_spiral_turns: Option<u8>,
+    /// Choose the seed of the noise pattern [default: random]
+    #[arg(long)]
+    char_noise_seed: Option<u64>,
+    /// Choose the frequency of the noise pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    char_noise_frequency: Option<u8>,
+    /// Choose the octave count of the noise pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..=8))]
+    char_noise_octaves: Option<u8>,
+    /// Choose the seed of the dissolve pattern [default: random]
+    #[arg(long)]
+    char_dissolve_seed: Option<u64>,
+    /// Choose the angle of the line pattern [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(u16).range(0..360))]
+    char_angle: u16,
+    /// Choose the rotation of the pattern [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(u16).range(0..360))]
+    char_rotation: u16,
+    /// Choose how far the pattern rotates during the animation [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(i16).range(-3600..=3600))]
+    char_spin: i16,
+    /// Choose the colors used for the pattern, either a pallet or a list like '#ff0000,rgb(0,0,255),dark_red,ansi:202'
+    #[arg(long, value_parser = PalletParser)]
+    colors: Option<Pallet>,
+    /// Choose the pallets of the random choices, like 'red,blue,rainbow' [default: all]
+    #[arg(long, value_enum, value_delimiter = ',')]
+    colors_from: Vec<PalletEnum>,
+    /// Exclude pallets from the random choices, like 'gray,dark-blue'
+    #[arg(long, value_enum, value_delimiter = ',')]
+    exclude_colors: Vec<PalletEnum>,
+    /// Choose how to mix neighbouring colors of the pallet
+    #[arg(long, value_enum, default_value_t = Interpolation::None)]
+    interpolation: Interpolation,
+    /// Choose the colors supported by the terminal [default: detected]
     #[arg(long, value_enum)]
-    colors: Option<PalletEnum>,
+    color_depth: Option<ColorDepth>,
     /// Choose the fill pattern
     #[arg(long, value_enum)]
     color_pattern: Option<PatternEnum>,
@@ -83,10 +211,46 @@ struct Args {
     /// Choose the segment count of the fill pattern [default: 1-4]
     #[arg(long, value_parser = value_parser!(u8).range(1..255))]
     color_segments: Option<u8>,
+    /// Choose the arm count of the spiral fill pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    color_spiral_arms: Option<u8>,
+    /// Choose the turn count of the spiral fill pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    color_spiral_turns: Option<u8>,
+    /// Choose the seed of the noise fill pattern [default: random]
+    #[arg(long)]
+    color_noise_seed: Option<u64>,
+    /// Choose the frequency of the noise fill pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..255))]
+    color_noise_frequency: Option<u8>,
+    /// Choose the octave count of the noise fill pattern [default: 1-4]
+    #[arg(long, value_parser = value_parser!(u8).range(1..=8))]
+    color_noise_octaves: Option<u8>,
+    /// Choose the seed of the dissolve fill pattern [default: random]
+    #[arg(long)]
+    color_dissolve_seed: Option<u64>,
+    /// Choose the angle of the line fill pattern [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(u16).range(0..360))]
+    color_angle: u16,
+    /// Choose the rotation of the fill pattern [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(u16).range(0..360))]
+    color_rotation: u16,
+    /// Choose how far the fill pattern rotates during the animation [degrees]
+    #[arg(long, default_value_t = 0, value_parser = value_parser!(i16).range(-3600..=3600))]
+    color_spin: i16,
+    /// Choose the background colors of the drawn chars, like --colors [default: none]
+    #[arg(long, value_parser = PalletParser)]
+    background_colors: Option<Pallet>,
+    /// Choose the background pattern, which takes the shape of the fill pattern
+    #[arg(long, value_enum)]
+    background_pattern: Option<PatternEnum>,
+    /// The raw values of the arguments which differ from their default.
+    #[arg(skip)]
+    changed: Vec<(String, String)>,
 }
 
 /// All color pallets.
-#[derive(ValueEnum, Copy, Clone)]
+#[derive(ValueEnum, Copy, Clone, PartialEq, Debug)]
 enum PalletEnum {
     Red,
     Yellow,
@@ -114,6 +278,17 @@ enum PalletEnum {
     Gray,
 }
 
+/// A pallet chosen on the command line.
+#[derive(Clone, PartialEq, Debug)]
+enum Pallet {
+    Preset(PalletEnum),
+    Custom(Vec<Color>),
+}
+
+/// A parser for a [Pallet].
+#[derive(Clone)]
+struct PalletParser;
+
 /// All possible [Pattern]s.
 #[derive(ValueEnum, Copy, Clone, PartialEq, Debug)]
 enum PatternEnum {
@@ -121,10 +296,13 @@ enum PatternEnum {
     Line,
     Rhombus,
     Wheel,
+    Spiral,
+    Noise,
+    Dissolve,
 }
 
 /// A configuration for a composed [Pattern].
-#[derive(derive_more::Constructor)]
+#[derive(PartialEq, Debug)]
 struct PatternConfig {
     pattern: PatternEnum,
     shift: bool,
@@ -132,36 +310,307 @@ struct PatternConfig {
     swap: bool,
     segments: f32,
     shrink: f32,
+    arms: f32,
+    turns: f32,
+    noise_seed: u64,
+    frequency: f32,
+    octaves: u8,
+    dissolve_seed: u64,
+    angle: f32,
+    rotation: f32,
+    spin: f32,
+}
+
+/// An inclusive range for random values.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Range {
+    min: u8,
+    max: u8,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self { min: 1, max: 4 }
+    }
+}
+
+impl Range {
+    /// Returns a random value in the range.
+    fn sample(&self, rng: &mut impl Rng) -> u8 {
+        rng.gen_range(self.min..=self.max)
+    }
 }
 
 impl Args {
+    /// Parses the arguments and keeps the ones which differ from their default.
+    fn try_parse_changed<I, T>(argv: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        let command = Args::command();
+        let matches = command.clone().try_get_matches_from(argv)?;
+        let mut args = Args::from_arg_matches(&matches)?;
+
+        args.changed = changed_args(&command, &matches);
+        Ok(args)
+    }
+
     /// Returns the configuration for the char [Pattern].
-    fn char_config(&self, rng: &mut impl Rng) -> PatternConfig {
-        PatternConfig::new(
-            choose(self.char_pattern, rng),
-            true,
-            self.char_invert.unwrap_or(rng.gen()),
-            self.char_swap.unwrap_or(rng.gen()),
-            self.char_segments.unwrap_or(rng.gen_range(1..=4)) as f32,
-            self.char_shrink.unwrap_or(rng.gen_range(1..=4)) as f32,
-        )
+    fn char_config(&self, rng: &mut impl Rng) -> Result<PatternConfig, Error> {
+        Ok(PatternConfig {
+            pattern: choose(self.char_pattern, |p| self.pattern_weight(p), rng)?,
+            shift: true,
+            invert: self.char_invert.unwrap_or(rng.gen()),
+            swap: self.char_swap.unwrap_or(rng.gen()),
+            segments: self
+                .char_segments
+                .unwrap_or(self.segments_range.sample(rng)) as f32,
+            shrink: self.char_shrink.unwrap_or(self.shrink_range.sample(rng)) as f32,
+            arms: self.char_spiral_arms.unwrap_or(rng.gen_range(1..=4)) as f32,
+            turns: self.char_spiral_turns.unwrap_or(rng.gen_range(1..=4)) as f32,
+            noise_seed: self.char_noise_seed.unwrap_or(rng.gen()),
+            frequency: self.char_noise_frequency.unwrap_or(rng.gen_range(1..=4)) as f32,
+            octaves: self.char_noise_octaves.unwrap_or(rng.gen_range(1..=4)),
+            dissolve_seed: self.char_dissolve_seed.unwrap_or(rng.gen()),
+            angle: (self.char_angle as f32).to_radians(),
+            rotation: (self.char_rotation as f32).to_radians(),
+            spin: (self.char_spin as f32).to_radians(),
+        })
     }
 
     /// Returns the configuration for the color [Pattern].
-    fn color_config(&self, rng: &mut impl Rng) -> PatternConfig {
-        PatternConfig::new(
-            choose(self.color_pattern, rng),
-            self.color_shift.unwrap_or(rng.gen()),
-            self.color_invert.unwrap_or(rng.gen()),
-            self.color_swap.unwrap_or(rng.gen()),
-            self.color_segments.unwrap_or(rng.gen_range(1..=4)) as f32,
-            1.0,
-        )
-    }
-
-    /// Returns the colors for the [ColorConverter].
-    fn pallet(&self, rng: &mut impl Rng) -> Vec<Color> {
-        match choose(self.colors, rng) {
+    fn color_config(&self, rng: &mut impl Rng) -> Result<PatternConfig, Error> {
+        Ok(PatternConfig {
+            pattern: choose(self.color_pattern, |p| self.pattern_weight(p), rng)?,
+            shift: self.color_shift.unwrap_or(rng.gen()),
+            invert: self.color_invert.unwrap_or(rng.gen()),
+            swap: self.color_swap.unwrap_or(rng.gen()),
+            segments: self
+                .color_segments
+                .unwrap_or(self.segments_range.sample(rng)) as f32,
+            shrink: 1.0,
+            arms: self.color_spiral_arms.unwrap_or(rng.gen_range(1..=4)) as f32,
+            turns: self.color_spiral_turns.unwrap_or(rng.gen_range(1..=4)) as f32,
+            noise_seed: self.color_noise_seed.unwrap_or(rng.gen()),
+            frequency: self.color_noise_frequency.unwrap_or(rng.gen_range(1..=4)) as f32,
+            octaves: self.color_noise_octaves.unwrap_or(rng.gen_range(1..=4)),
+            dissolve_seed: self.color_dissolve_seed.unwrap_or(rng.gen()),
+            angle: (self.color_angle as f32).to_radians(),
+            rotation: (self.color_rotation as f32).to_radians(),
+            spin: (self.color_spin as f32).to_radians(),
+        })
+    }
+
+    /// Returns the [Pallet] with a random preset if none was chosen.
+    fn pallet(&self, rng: &mut impl Rng) -> Result<Pallet, Error> {
+        let preset = match &self.colors {
+            Some(Pallet::Custom(colors)) => return Ok(Pallet::Custom(colors.clone())),
+            Some(Pallet::Preset(preset)) => Some(*preset),
+            None => None,
+        };
+        let preset = choose(preset, |p| self.pallet_weight(p), rng)?;
+
+        Ok(Pallet::Preset(preset))
+    }
+
+    /// Returns the [Pallet] for the background [ColorConverter].
+    fn background_pallet(&self) -> Pallet {
+        match &self.background_colors {
+            Some(pallet) => pallet.clone(),
+            None => Pallet::Custom(vec![Reset]),
+        }
+    }
+
+    /// Returns the configuration for the background [Pattern].
+    ///
+    /// Only the pattern is chosen, the rest is taken from the color [Pattern].
+    fn background_config(
+        &self,
+        color: &PatternConfig,
+        rng: &mut impl Rng,
+    ) -> Result<PatternConfig, Error> {
+        Ok(PatternConfig {
+            pattern: choose(self.background_pattern, |p| self.pattern_weight(p), rng)?,
+            ..*color
+        })
+    }
+
+    /// Returns the weight of a random [PatternEnum].
+    fn pattern_weight(&self, pattern: &PatternEnum) -> u32 {
+        if self.exclude_pattern.contains(pattern) {
+            return 0;
+        }
+        self.pattern_weights
+            .iter()
+            .rev()
+            .find(|(other, _)| other == pattern)
+            .map_or(1, |(_, weight)| *weight as u32)
+    }
+
+    /// Returns the weight of a random [PalletEnum].
+    fn pallet_weight(&self, pallet: &PalletEnum) -> u32 {
+        let included = self.colors_from.is_empty() || self.colors_from.contains(pallet);
+
+        (included && !self.exclude_colors.contains(pallet)) as u32
+    }
+
+    /// Checks that every random choice has at least one option.
+    fn validate(&self) -> Result<(), Error> {
+        let chosen = self.char_pattern.is_some()
+            && self.color_pattern.is_some()
+            && (self.background_pattern.is_some() || self.background_colors.is_none());
+
+        if !chosen
+            && PatternEnum::value_variants()
+                .iter()
+                .all(|pattern| self.pattern_weight(pattern) == 0)
+        {
+            return Err("All patterns are excluded.".into());
+        }
+        if self.colors.is_none()
+            && PalletEnum::value_variants()
+                .iter()
+                .all(|pallet| self.pallet_weight(pallet) == 0)
+        {
+            return Err("All pallets are excluded.".into());
+        }
+        Ok(())
+    }
+
+    /// Returns the [ColorDepth] of the terminal.
+    fn color_depth(&self) -> ColorDepth {
+        self.color_depth
+            .unwrap_or_else(|| ColorDepth::detect(|name| std::env::var(name).ok()))
+    }
+
+    /// Returns the seed for the random choices.
+    fn seed(&self) -> u64 {
+        self.seed.unwrap_or_else(|| thread_rng().gen())
+    }
+
+    /// Returns the arguments which reproduce the resolved random choices.
+    fn resolved(
+        &self,
+        char: &PatternConfig,
+        color: &PatternConfig,
+        pallet: &Pallet,
+        background: Option<&PatternConfig>,
+    ) -> Vec<(String, Value)> {
+        let mut choices = char.to_args("char");
+        choices.push(("colors".to_string(), Value::Str(pallet.to_arg())));
+        choices.extend(color.to_args("color"));
+
+        if let (Some(pallet), Some(background)) = (&self.background_colors, background) {
+            choices.push(("background-colors".to_string(), Value::Str(pallet.to_arg())));
+            choices.extend(background.to_args("background"));
+        }
+        // Every random choice has an argument, so the seed isn't needed.
+        let mut args: Vec<_> = self
+            .changed
+            .iter()
+            .filter(|(name, _)| choices.iter().all(|(other, _)| other != name))
+            .map(|(name, value)| (name.clone(), Value::from_raw(value)))
+            .collect();
+
+        args.extend(choices);
+        args
+    }
+
+    /// Returns the duration for the [Timer].
+    fn duration(&self) -> Duration {
+        Duration::from_millis(self.duration)
+    }
+
+    /// Returns the delay for the [Timer].
+    fn delay(&self) -> Duration {
+        Duration::from_nanos(1_000_000_000 / self.fps)
+    }
+}
+
+impl PatternConfig {
+    /// Creates a new base [Pattern].
+    fn create_base(&self) -> Box<dyn PatternFactory> {
+        match self.pattern {
+            PatternEnum::Circle => Box::new(CircleFactory::new()),
+            PatternEnum::Line => Box::new(LineFactory::new(self.angle)),
+            PatternEnum::Rhombus => Box::new(RhombusFactory::new()),
+            PatternEnum::Wheel => Box::new(WheelFactory::new()),
+            PatternEnum::Spiral => Box::new(SpiralFactory::new(self.arms, self.turns)),
+            PatternEnum::Noise => Box::new(NoiseFactory::new(
+                self.noise_seed,
+                self.frequency,
+                self.octaves,
+            )),
+            PatternEnum::Dissolve => Box::new(DissolveFactory::new(self.dissolve_seed)),
+        }
+    }
+
+    /// Creates a new composed [Pattern].
+    fn create(&self) -> Box<dyn PatternFactory> {
+        let mut pattern = self.create_base();
+
+        if self.shift {
+            pattern = Box::new(ShiftFactory::new(pattern))
+        }
+        if self.invert {
+            pattern = Box::new(InvertFactory::new(pattern))
+        }
+        if self.swap {
+            pattern = Box::new(SwapFactory::new(pattern))
+        }
+        if self.rotation != 0.0 || self.spin != 0.0 {
+            pattern = Box::new(RotateFactory::new(pattern, self.rotation, self.spin))
+        }
+        if self.segments != 1.0 {
+            pattern = Box::new(SegmentsFactory::new(pattern, self.segments));
+        }
+        if self.shrink != 1.0 {
+            pattern = Box::new(ShrinkFactory::new(pattern, self.shrink));
+        }
+        pattern
+    }
+}
+
+impl PatternConfig {
+    /// Returns the arguments with the given prefix which recreate this configuration.
+    fn to_args(&self, prefix: &str) -> Vec<(String, Value)> {
+        let degrees = |radians: f32| Value::Int(radians.to_degrees().round() as i64);
+        let args = [
+            ("pattern", Value::Str(name(&self.pattern))),
+            ("shift", Value::Bool(self.shift)),
+            ("invert", Value::Bool(self.invert)),
+            ("swap", Value::Bool(self.swap)),
+            ("segments", Value::Int(self.segments as i64)),
+            ("shrink", Value::Int(self.shrink as i64)),
+            ("spiral-arms", Value::Int(self.arms as i64)),
+            ("spiral-turns", Value::Int(self.turns as i64)),
+            ("noise-seed", Value::Str(self.noise_seed.to_string())),
+            ("noise-frequency", Value::Int(self.frequency as i64)),
+            ("noise-octaves", Value::Int(self.octaves as i64)),
+            ("dissolve-seed", Value::Str(self.dissolve_seed.to_string())),
+            ("angle", degrees(self.angle)),
+            ("rotation", degrees(self.rotation)),
+            ("spin", degrees(self.spin)),
+        ];
+        let command = Args::command();
+
+        // Not every pattern has all arguments, like the fixed shift of the char pattern.
+        args.into_iter()
+            .map(|(name, value)| (format!("{}-{}", prefix, name), value))
+            .filter(|(name, _)| {
+                command
+                    .get_arguments()
+                    .any(|arg| arg.get_long() == Some(name))
+            })
+            .collect()
+    }
+}
+
+impl PalletEnum {
+    /// Returns the colors of the pallet.
+    fn colors(&self) -> Vec<Color> {
+        match self {
             PalletEnum::Red => vec![DarkRed, Red, White],
             PalletEnum::Yellow => vec![DarkYellow, Yellow, White],
             PalletEnum::Green => vec![DarkGreen, Green, White],
@@ -196,79 +645,447 @@ impl Args {
         }
     }
 
-    /// Returns the duration for the [Timer].
-    fn duration(&self) -> Duration {
-        Duration::from_millis(self.duration)
+    /// Returns the RGB counterparts of the colors for truecolor terminals.
+    fn truecolors(&self) -> Vec<Color> {
+        let hex: &[u32] = match self {
+            PalletEnum::Red => &[0x7a0c0c, 0xe53935, 0xffebee],
+            PalletEnum::Yellow => &[0x8a6d00, 0xfdd835, 0xfffde7],
+            PalletEnum::Green => &[0x1b5e20, 0x43a047, 0xe8f5e9],
+            PalletEnum::Blue => &[0x0d2b6b, 0x1e88e5, 0xe3f2fd],
+            PalletEnum::Magenta => &[0x6a0f6a, 0xd040d0, 0xfbe9fb],
+            PalletEnum::Cyan => &[0x005f66, 0x00bcd4, 0xe0f7fa],
+            PalletEnum::Rainbow => &[0xe53935, 0xfdd835, 0x43a047, 0x1e88e5, 0x00bcd4, 0xd040d0],
+
+            PalletEnum::DarkRed => &[0x000000, 0x7a0c0c, 0xe53935],
+            PalletEnum::DarkYellow => &[0x000000, 0x8a6d00, 0xfdd835],
+            PalletEnum::DarkGreen => &[0x000000, 0x1b5e20, 0x43a047],
+            PalletEnum::DarkBlue => &[0x000000, 0x0d2b6b, 0x1e88e5],
+            PalletEnum::DarkMagenta => &[0x000000, 0x6a0f6a, 0xd040d0],
+            PalletEnum::DarkCyan => &[0x000000, 0x005f66, 0x00bcd4],
+            PalletEnum::DarkRainbow => {
+                &[0x7a0c0c, 0x8a6d00, 0x1b5e20, 0x0d2b6b, 0x005f66, 0x6a0f6a]
+            }
+
+            PalletEnum::RedYellow => &[0xe53935, 0x7a0c0c, 0x8a6d00, 0xfdd835],
+            PalletEnum::YellowGreen => &[0xfdd835, 0x8a6d00, 0x1b5e20, 0x43a047],
+            PalletEnum::GreenBlue => &[0x43a047, 0x1b5e20, 0x0d2b6b, 0x1e88e5],
+            PalletEnum::BlueCyan => &[0x1e88e5, 0x0d2b6b, 0x005f66, 0x00bcd4],
+            PalletEnum::CyanMagenta => &[0x00bcd4, 0x005f66, 0x6a0f6a, 0xd040d0],
+            PalletEnum::MagentaRed => &[0xd040d0, 0x6a0f6a, 0x7a0c0c, 0xe53935],
+
+            PalletEnum::Gray => &[0x000000, 0x555555, 0xaaaaaa, 0xffffff],
+        };
+        hex.iter()
+            .map(|hex| Rgb {
+                r: (hex >> 16) as u8,
+                g: (hex >> 8) as u8,
+                b: *hex as u8,
+            })
+            .collect()
     }
 
-    /// Returns the delay for the [Timer].
-    fn delay(&self) -> Duration {
-        Duration::from_nanos(1_000_000_000 / self.fps)
+    /// Returns true if the last color should be mixed with the first one.
+    fn cyclic(&self) -> bool {
+        matches!(self, PalletEnum::Rainbow | PalletEnum::DarkRainbow)
     }
 }
 
-impl PatternConfig {
-    /// Creates a new base [Pattern].
-    fn create_base(&self) -> Box<dyn PatternFactory> {
-        match self.pattern {
-            PatternEnum::Circle => Box::new(CircleFactory::new()),
-            PatternEnum::Line => Box::new(LineFactory::new()),
-            PatternEnum::Rhombus => Box::new(RhombusFactory::new()),
-            PatternEnum::Wheel => Box::new(WheelFactory::new()),
+impl Pallet {
+    /// Returns the colors of the pallet.
+    ///
+    /// Presets keep the colors of the terminal theme,
+    /// unless they are mixed on a truecolor terminal.
+    fn colors(&self, depth: ColorDepth, interpolation: Interpolation) -> Vec<Color> {
+        let mixed = depth == ColorDepth::Truecolor && interpolation != Interpolation::None;
+
+        match self {
+            Pallet::Preset(preset) if mixed => preset.truecolors(),
+            Pallet::Preset(preset) => preset.colors(),
+            Pallet::Custom(colors) => colors.clone(),
         }
     }
 
-    /// Creates a new composed [Pattern].
-    fn create(&self) -> Box<dyn PatternFactory> {
-        let mut pattern = self.create_base();
+    /// Returns true if the last color should be mixed with the first one.
+    fn cyclic(&self) -> bool {
+        match self {
+            Pallet::Preset(preset) => preset.cyclic(),
+            Pallet::Custom(_) => false,
+        }
+    }
+}
 
-        if self.shift {
-            pattern = Box::new(ShiftFactory::new(pattern))
+impl Pallet {
+    /// Returns the argument which recreates this pallet.
+    fn to_arg(&self) -> String {
+        match self {
+            Pallet::Preset(preset) => name(preset),
+            Pallet::Custom(colors) => colors
+                .iter()
+                .map(|color| format_color(*color))
+                .collect::<Vec<_>>()
+                .join(","),
         }
-        if self.invert {
-            pattern = Box::new(InvertFactory::new(pattern))
+    }
+}
+
+impl TypedValueParser for PalletParser {
+    type Value = Pallet;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        _: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Pallet, clap::Error> {
+        let value = value
+            .to_str()
+            .ok_or_else(|| clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd))?;
+
+        if let Ok(preset) = PalletEnum::from_str(value, true) {
+            return Ok(Pallet::Preset(preset));
         }
-        if self.swap {
-            pattern = Box::new(SwapFactory::new(pattern))
+        split_colors(value)
+            .into_iter()
+            .map(parse_color)
+            .collect::<Result<_, _>>()
+            .map(Pallet::Custom)
+            .map_err(|msg| clap::Error::raw(ErrorKind::InvalidValue, msg + "\n").with_cmd(cmd))
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            PalletEnum::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
+/// Splits a list of colors at all commas outside of parentheses.
+fn split_colors(list: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, char) in list.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&list[start..i]);
+                start = i + 1;
+            }
+            _ => (),
         }
-        if self.segments != 1.0 {
-            pattern = Box::new(SegmentsFactory::new(pattern, self.segments));
+    }
+    parts.push(&list[start..]);
+    parts
+}
+
+/// Parses a color like `#ff8000`, `rgb(255, 128, 0)`, `dark_red` or `ansi:202`.
+fn parse_color(spec: &str) -> Result<Color, String> {
+    let spec = spec.trim();
+    let invalid = || format!("Invalid color: '{}'", spec);
+
+    if let Some(hex) = spec.strip_prefix('#') {
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
         }
-        if self.shrink != 1.0 {
-            pattern = Box::new(ShrinkFactory::new(pattern, self.shrink));
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+
+        Ok(Rgb {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        })
+    } else if let Some(channels) = spec
+        .strip_prefix("rgb(")
+        .and_then(|spec| spec.strip_suffix(')'))
+    {
+        let channels: Vec<u8> = channels
+            .split(',')
+            .map(|channel| channel.trim().parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid())?;
+
+        match channels[..] {
+            [r, g, b] => Ok(Rgb { r, g, b }),
+            _ => Err(invalid()),
         }
-        pattern
+    } else if let Some(index) = spec.strip_prefix("ansi:") {
+        index.trim().parse().map(AnsiValue).map_err(|_| invalid())
+    } else {
+        Color::try_from(spec).map_err(|_| invalid())
     }
 }
 
-/// Returns the value of the [Option] or a random enum variant.
-fn choose<TValue: ValueEnum, TRand: Rng>(opt: Option<TValue>, rng: &mut TRand) -> TValue {
-    match opt {
-        Some(value) => value.clone(),
-        None => TValue::value_variants().iter().choose(rng).unwrap().clone(),
+/// Formats a color so that it can be parsed by [parse_color].
+fn format_color(color: Color) -> String {
+    match color {
+        Rgb { r, g, b } => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        AnsiValue(index) => format!("ansi:{}", index),
+        color => {
+            let mut name = String::new();
+
+            for char in format!("{:?}", color).chars() {
+                if char.is_uppercase() && !name.is_empty() {
+                    name.push('_');
+                }
+                name.push(char.to_ascii_lowercase());
+            }
+            name
+        }
     }
 }
 
+/// Parses attributes like `bold+italic`.
+fn parse_attributes(spec: &str) -> Result<Attributes, String> {
+    let mut attributes = Attributes::default();
+
+    for name in spec.split('+') {
+        attributes.set(match name.trim() {
+            "none" => continue,
+            "bold" => Attribute::Bold,
+            "dim" => Attribute::Dim,
+            "italic" => Attribute::Italic,
+            "underline" => Attribute::Underlined,
+            "reverse" => Attribute::Reverse,
+            "blink" => Attribute::SlowBlink,
+            "crossed-out" => Attribute::CrossedOut,
+            name => return Err(format!("Invalid attribute: '{}'", name)),
+        });
+    }
+    Ok(attributes)
+}
+
+/// Returns the name of an enum variant on the command line.
+fn name(value: &impl ValueEnum) -> String {
+    value.to_possible_value().unwrap().get_name().to_string()
+}
+
+/// Returns the value of the [Option] or a random enum variant chosen by its weight.
+fn choose<TValue: ValueEnum, TRand: Rng>(
+    opt: Option<TValue>,
+    weight: impl Fn(&TValue) -> u32,
+    rng: &mut TRand,
+) -> Result<TValue, Error> {
+    // The random choice is always made so that the following choices
+    // of a seed are the same whether or not the value is set.
+    let random = TValue::value_variants()
+        .choose_weighted(rng, weight)
+        .ok()
+        .cloned();
+
+    opt.or(random)
+        .ok_or_else(|| "All variants are excluded.".into())
+}
+
+/// Parses the weight of a pattern like `circle=3`.
+fn parse_pattern_weight(spec: &str) -> Result<(PatternEnum, u16), String> {
+    let invalid = || format!("Invalid weight: '{}'", spec);
+    let (name, weight) = spec.split_once('=').ok_or_else(invalid)?;
+    let pattern = PatternEnum::from_str(name.trim(), true).map_err(|_| invalid())?;
+    let weight = weight.trim().parse().map_err(|_| invalid())?;
+
+    Ok((pattern, weight))
+}
+
+/// Parses a range like `2-6` or a single value like `3`.
+fn parse_range(spec: &str) -> Result<Range, String> {
+    let invalid = || format!("Invalid range: '{}'", spec);
+    let (min, max) = spec.split_once('-').unwrap_or((spec, spec));
+    let min = min.trim().parse().map_err(|_| invalid())?;
+    let max = max.trim().parse().map_err(|_| invalid())?;
+
+    if min == 0 || min > max {
+        return Err(invalid());
+    }
+    Ok(Range { min, max })
+}
+
+/// Returns the raw values of the parsed arguments which differ from their default.
+fn changed_args(command: &Command, matches: &ArgMatches) -> Vec<(String, String)> {
+    let join = |values: &mut dyn Iterator<Item = &OsStr>| {
+        values
+            .map(|value| value.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+    let mut args = Vec::new();
+
+    for arg in command.get_arguments() {
+        let (id, Some(name)) = (arg.get_id().as_str(), arg.get_long()) else {
+            continue;
+        };
+        if CLI_ONLY.contains(&name) || matches.value_source(id) != Some(ValueSource::CommandLine) {
+            continue;
+        }
+        let Some(mut values) = matches.get_raw(id) else {
+            continue;
+        };
+        let value = join(&mut values);
+
+        if value != join(&mut arg.get_default_values().iter().map(|value| &**value)) {
+            args.push((name.to_string(), value));
+        }
+    }
+    args
+}
+
+/// Returns the last value of a long option on the command line
+/// without parsing the other arguments.
+fn cli_value(cli: &[OsString], name: &str) -> Option<String> {
+    let option = format!("--{}", name);
+    let prefix = format!("--{}=", name);
+    let mut value = None;
+    let mut args = cli.iter().skip(1).map(|arg| arg.to_string_lossy());
+
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            break;
+        } else if arg == option {
+            value = args.next().map(String::from);
+        } else if let Some(rest) = arg.strip_prefix(&prefix) {
+            value = Some(rest.to_string());
+        }
+    }
+    value
+}
+
+/// Returns the arguments of a preset after checking their values.
+fn preset_args(presets: &[Preset], name: &str) -> Result<Vec<String>, Error> {
+    let preset = presets
+        .iter()
+        .find(|preset| preset.name == name)
+        .ok_or_else(|| format!("Unknown preset: '{}'", name))?;
+
+    Args::try_parse_from(std::iter::once("wipe").chain(preset.args.iter().map(String::as_str)))
+        .map_err(|err| {
+            let msg = err.to_string();
+            let msg = msg.lines().next().unwrap_or_default();
+            format!(
+                "Invalid preset '{}': {}",
+                name,
+                msg.trim_start_matches("error: ")
+            )
+        })?;
+    Ok(preset.args.clone())
+}
+
+/// Parses the command line arguments on top of the preset and the config file.
+///
+/// Returns the arguments and all available presets.
+fn parse_args() -> Result<(Args, Vec<Preset>), Error> {
+    let cli: Vec<OsString> = std::env::args_os().collect();
+    let path = match cli_value(&cli, "config") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => config_path(|name| std::env::var(name).ok()).filter(|path| path.exists()),
+    };
+    let content = path
+        .as_ref()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read '{}': {}", path.display(), err))
+        })
+        .transpose()?;
+    let invalid = |err: Error| {
+        let path = path.as_ref().map(|path| path.display().to_string());
+        format!("Invalid config '{}': {:?}", path.unwrap_or_default(), err)
+    };
+    let command = Args::command();
+    let file = match &content {
+        Some(content) => config_args(content, &command).map_err(invalid)?,
+        None => Vec::new(),
+    };
+    let presets = presets(content.as_deref(), &command).map_err(invalid)?;
+    let preset = match cli_value(&cli, "preset") {
+        Some(name) => preset_args(&presets, &name)?,
+        None => Vec::new(),
+    };
+
+    // Later arguments override earlier ones.
+    let defaults = file.iter().chain(&preset).map(OsString::from);
+    let args = Args::try_parse_changed(
+        cli[..1]
+            .iter()
+            .cloned()
+            .chain(defaults)
+            .chain(cli[1..].iter().cloned()),
+    )
+    .unwrap_or_else(|err| err.exit());
+    args.validate()?;
+    Ok((args, presets))
+}
+
 fn main() -> Result<(), Error> {
-    let args = Args::parse();
-    let rand = &mut thread_rng();
+    let (args, presets) = parse_args()?;
+
+    if args.list_presets {
+        for preset in presets {
+            println!("{:<10} {}", preset.name, preset.args.join(" "));
+        }
+        return Ok(());
+    }
+    let seed = args.seed();
+    let rand = &mut ChaCha8Rng::seed_from_u64(seed);
+
+    let char = args.char_config(rand)?;
+    let color = args.color_config(rand)?;
+    let pallet = args.pallet(rand)?;
+    let background = args
+        .background_colors
+        .is_some()
+        .then(|| args.background_config(&color, rand))
+        .transpose()?;
+
+    if let Some(explain) = args.explain {
+        let resolved = args.resolved(&char, &color, &pallet, background.as_ref());
 
-    let char = args.char_config(rand).create();
-    let color = args.color_config(rand).create();
-    let pallet = args.pallet(rand);
+        println!("{}", explain.format(&resolved));
+        return Ok(());
+    }
+    let char = char.create();
+    let color = color.create();
+    let background = background.map(|background| background.create());
+    let background_pallet = args.background_pallet();
     let duration = args.duration();
     let delay = args.delay();
+    let color_depth = args.color_depth();
 
-    let sampler = SamplerFactoryImpl::new(char, color);
+    let sampler = SamplerFactoryImpl::new(char, color, background);
     let char_converter = CharConverterImpl::new(args.chars);
-    let color_converter = ColorConverterImpl::new(pallet);
-    let converter = ConverterImpl::new(char_converter, color_converter);
+    let color_converter = ColorConverterImpl::new(
+        pallet.colors(color_depth, args.interpolation),
+        pallet.cyclic(),
+        args.interpolation,
+    );
+    let color_converter = ColorDepthConverter::new(color_converter, color_depth);
+    let background_converter = ColorConverterImpl::new(
+        background_pallet.colors(color_depth, args.interpolation),
+        background_pallet.cyclic(),
+        args.interpolation,
+    );
+    let background_converter = ColorDepthConverter::new(background_converter, color_depth);
+    let attribute_converter = AttributeConverterImpl::new(args.attributes);
+    let converter = ConverterImpl::new(
+        char_converter,
+        color_converter,
+        background_converter,
+        attribute_converter,
+    );
     let term = TerminalImpl::new(stdout());
-    let printer = PrinterImpl::new(term)?;
-    let renderer = RendererImpl::new(sampler, converter, printer)?;
+    let printer = PrinterImpl::new(term, args.sync_output)?;
+    let renderer = RendererImpl::new(
+        sampler,
+        converter,
+        printer,
+        args.render_mode,
+        args.alternate_screen,
+        args.finish,
+    )?;
 
     let clock = ClockImpl::new();
-    let executor = Executor::new(clock, duration, delay);
+    let executor = Executor::new(clock, duration, delay, args.easing);
 
     let src = CancellationTokenSource::new();
     let token = src.token().clone();
@@ -276,7 +1093,12 @@ fn main() -> Result<(), Error> {
     ctrlc::set_handler(move || {
         src.cancel();
     })?;
-    executor.run(renderer, &token)
+    executor.run(renderer, &token)?;
+
+    if args.print_seed {
+        println!("{}", seed);
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -284,6 +1106,7 @@ mod test {
     use super::*;
     use approx::*;
     use rand::rngs::mock::StepRng;
+    use std::f32::consts::PI;
 
     #[test]
     fn args_pallet_all_defined() {
@@ -291,13 +1114,684 @@ mod test {
 
         for value in PalletEnum::value_variants() {
             let args = Args {
-                colors: Some(*value),
+                colors: Some(Pallet::Preset(*value)),
                 ..Args::default()
             };
-            assert!(args.pallet(rand).len() > 0);
+            assert!(!args
+                .pallet(rand)
+                .unwrap()
+                .colors(ColorDepth::Ansi16, Interpolation::None)
+                .is_empty());
+            assert!(!args
+                .pallet(rand)
+                .unwrap()
+                .colors(ColorDepth::Truecolor, Interpolation::Srgb)
+                .is_empty());
         }
     }
 
+    #[test]
+    fn args_pallet_custom() {
+        let rand = &mut StepRng::new(1, 1);
+        let args = Args {
+            colors: Some(Pallet::Custom(vec![Red, Blue])),
+            ..Args::default()
+        };
+        assert_eq!(
+            vec![Red, Blue],
+            args.pallet(rand)
+                .unwrap()
+                .colors(ColorDepth::Truecolor, Interpolation::None)
+        );
+    }
+
+    #[test]
+    fn pallet_truecolor() {
+        let pallet = Pallet::Preset(PalletEnum::Red);
+
+        assert_eq!(
+            vec![DarkRed, Red, White],
+            pallet.colors(ColorDepth::Ansi256, Interpolation::Oklab)
+        );
+        assert_eq!(
+            vec![DarkRed, Red, White],
+            pallet.colors(ColorDepth::Truecolor, Interpolation::None)
+        );
+        assert_eq!(
+            Rgb {
+                r: 0xe5,
+                g: 0x39,
+                b: 0x35
+            },
+            pallet.colors(ColorDepth::Truecolor, Interpolation::Srgb)[1]
+        );
+    }
+
+    #[test]
+    fn pallet_cyclic() {
+        assert!(Pallet::Preset(PalletEnum::Rainbow).cyclic());
+        assert!(!Pallet::Preset(PalletEnum::Red).cyclic());
+        assert!(!Pallet::Custom(vec![Red, Blue]).cyclic());
+    }
+
+    #[test]
+    fn parse_pallet_preset() {
+        let args = Args::try_parse_from(["wipe", "--colors", "dark-red"]).unwrap();
+
+        assert_eq!(Some(Pallet::Preset(PalletEnum::DarkRed)), args.colors);
+    }
+
+    #[test]
+    fn parse_pallet_custom() {
+        let args = Args::try_parse_from(["wipe", "--colors", "#ff0000,#00FF80"]).unwrap();
+        let colors = vec![
+            Rgb { r: 255, g: 0, b: 0 },
+            Rgb {
+                r: 0,
+                g: 255,
+                b: 128,
+            },
+        ];
+
+        assert_eq!(Some(Pallet::Custom(colors)), args.colors);
+    }
+
+    #[test]
+    fn parse_pallet_invalid() {
+        assert!(Args::try_parse_from(["wipe", "--colors", "#ff00"]).is_err());
+        assert!(Args::try_parse_from(["wipe", "--colors", "#ff0000,"]).is_err());
+        assert!(Args::try_parse_from(["wipe", "--colors", "purple"]).is_err());
+    }
+
+    #[test]
+    fn parse_pallet_mixed() {
+        let args =
+            Args::try_parse_from(["wipe", "--colors", "rgb(1, 2, 3),dark_red,ansi:202"]).unwrap();
+        let colors = vec![Rgb { r: 1, g: 2, b: 3 }, DarkRed, AnsiValue(202)];
+
+        assert_eq!(Some(Pallet::Custom(colors)), args.colors);
+    }
+
+    #[test]
+    fn split_colors_respects_parentheses() {
+        assert_eq!(
+            vec!["#000000", "rgb(1,2,3)", " red"],
+            split_colors("#000000,rgb(1,2,3), red")
+        );
+    }
+
+    #[test]
+    fn parse_color_hex() {
+        assert_eq!(
+            Ok(Rgb {
+                r: 255,
+                g: 128,
+                b: 0
+            }),
+            parse_color("#ff8000")
+        );
+        assert!(parse_color("#ff800").is_err());
+        assert!(parse_color("#ff800g").is_err());
+    }
+
+    #[test]
+    fn parse_color_rgb() {
+        assert_eq!(Ok(Rgb { r: 4, g: 5, b: 6 }), parse_color("rgb(4,5, 6)"));
+        assert!(parse_color("rgb(4,5)").is_err());
+        assert!(parse_color("rgb(4,5,256)").is_err());
+    }
+
+    #[test]
+    fn parse_color_name() {
+        assert_eq!(Ok(DarkRed), parse_color("dark_red"));
+        assert_eq!(Ok(Grey), parse_color("grey"));
+        assert!(parse_color("purple").is_err());
+    }
+
+    #[test]
+    fn parse_color_ansi() {
+        assert_eq!(Ok(AnsiValue(202)), parse_color("ansi:202"));
+        assert!(parse_color("ansi:256").is_err());
+    }
+
+    #[test]
+    fn color_depth_override() {
+        let args = Args {
+            color_depth: Some(ColorDepth::Ansi256),
+            ..Args::default()
+        };
+        assert_eq!(ColorDepth::Ansi256, args.color_depth());
+    }
+
+    #[test]
+    fn parse_attributes_combined() {
+        let mut attributes = Attributes::default();
+        attributes.set(Attribute::Bold);
+        attributes.set(Attribute::Italic);
+
+        assert_eq!(Ok(attributes), parse_attributes("bold+italic"));
+    }
+
+    #[test]
+    fn parse_attributes_none() {
+        assert_eq!(Ok(Attributes::default()), parse_attributes("none"));
+    }
+
+    #[test]
+    fn parse_attributes_invalid() {
+        assert!(parse_attributes("bold+shiny").is_err());
+    }
+
+    #[test]
+    fn parse_attributes_list() {
+        let args = Args::try_parse_from(["wipe", "--attributes", "dim,none,bold"]).unwrap();
+        let expected = vec![
+            Attribute::Dim.into(),
+            Attributes::default(),
+            Attribute::Bold.into(),
+        ];
+        assert_eq!(expected, args.attributes);
+    }
+
+    #[test]
+    fn seed_fixed() {
+        let args = Args {
+            seed: Some(42),
+            ..Args::default()
+        };
+        assert_eq!(42, args.seed());
+    }
+
+    #[test]
+    fn seed_reproduces_choices() {
+        let args = Args::try_parse_from(["wipe", "--seed", "7"]).unwrap();
+        let first = &mut ChaCha8Rng::seed_from_u64(args.seed());
+        let second = &mut ChaCha8Rng::seed_from_u64(args.seed());
+
+        assert_eq!(
+            args.char_config(first).unwrap(),
+            args.char_config(second).unwrap()
+        );
+        assert_eq!(
+            args.color_config(first).unwrap(),
+            args.color_config(second).unwrap()
+        );
+        assert_eq!(args.pallet(first).unwrap(), args.pallet(second).unwrap());
+    }
+
+    #[test]
+    fn seed_is_portable() {
+        // A seed printed by an older build has to reproduce the same choices.
+        let args = Args::default();
+        let rng = &mut ChaCha8Rng::seed_from_u64(1);
+        let char = args.char_config(rng).unwrap();
+
+        assert_eq!(PatternEnum::Wheel, char.pattern);
+        assert_eq!(15356091539799779292, char.noise_seed);
+    }
+
+    #[test]
+    fn format_color_parses() {
+        for color in [
+            Rgb {
+                r: 1,
+                g: 128,
+                b: 255,
+            },
+            AnsiValue(202),
+            DarkRed,
+            Grey,
+            DarkGrey,
+        ] {
+            assert_eq!(Ok(color), parse_color(&format_color(color)));
+        }
+    }
+
+    #[test]
+    fn pallet_to_arg() {
+        assert_eq!("dark-red", Pallet::Preset(PalletEnum::DarkRed).to_arg());
+        assert_eq!(
+            "#ff0000,ansi:4,dark_blue",
+            Pallet::Custom(vec![Rgb { r: 255, g: 0, b: 0 }, AnsiValue(4), DarkBlue]).to_arg()
+        );
+    }
+
+    #[test]
+    fn pattern_config_to_args() {
+        let args =
+            Args::try_parse_from(["wipe", "--char-spin", "90", "--color-shift", "true"]).unwrap();
+        let rng = &mut StepRng::new(1, 1);
+        let char = args.char_config(rng).unwrap().to_args("char");
+        let color = args.color_config(rng).unwrap().to_args("color");
+        let has = |args: &[(String, Value)], name: &str| args.iter().any(|(n, _)| n == name);
+
+        assert!(!has(&char, "char-shift"));
+        assert!(has(&char, "char-shrink"));
+        assert!(has(&color, "color-shift"));
+        assert!(!has(&color, "color-shrink"));
+        assert!(char.contains(&("char-spin".to_string(), Value::Int(90))));
+        assert!(color.contains(&("color-shift".to_string(), Value::Bool(true))));
+    }
+
+    #[test]
+    fn explain_reproduces_choices() {
+        let args = Args::try_parse_from(["wipe", "--background-colors", "red"]).unwrap();
+        let rng = &mut ChaCha8Rng::seed_from_u64(3);
+        let char = args.char_config(rng).unwrap();
+        let color = args.color_config(rng).unwrap();
+        let pallet = args.pallet(rng).unwrap();
+        let background = args.background_config(&color, rng).unwrap();
+        let resolved = args.resolved(&char, &color, &pallet, Some(&background));
+
+        let line = Explain::Args.format(&resolved);
+        let explained = Args::try_parse_from(line.split(' ')).unwrap();
+        // The choices don't depend on the seed.
+        let rng = &mut ChaCha8Rng::seed_from_u64(9);
+
+        assert_eq!(char, explained.char_config(rng).unwrap());
+        let explained_color = explained.color_config(rng).unwrap();
+
+        assert_eq!(color, explained_color);
+        assert_eq!(pallet, explained.pallet(rng).unwrap());
+        assert_eq!(
+            background,
+            explained.background_config(&explained_color, rng).unwrap()
+        );
+    }
+
+    #[test]
+    fn explain_keeps_changed_args() {
+        let cli = [
+            "wipe",
+            "--fps=30",
+            "--chars=01",
+            "--easing=steps(4)",
+            "--attributes=dim,bold",
+            "--alternate-screen",
+            "--finish=keep",
+            "--background-colors=red",
+            "--color-spin=-90",
+            "--color-pattern=line",
+            "--duration=2000",
+            "--preset=matrix",
+        ];
+        let args = Args::try_parse_changed(cli).unwrap();
+        let rng = &mut ChaCha8Rng::seed_from_u64(5);
+        let char = args.char_config(rng).unwrap();
+        let color = args.color_config(rng).unwrap();
+        let pallet = args.pallet(rng).unwrap();
+        let background = args.background_config(&color, rng).unwrap();
+        let resolved = args.resolved(&char, &color, &pallet, Some(&background));
+        let names: Vec<_> = resolved.iter().map(|(name, _)| name.as_str()).collect();
+
+        assert_eq!(
+            vec![
+                "fps",
+                "easing",
+                "chars",
+                "attributes",
+                "alternate-screen",
+                "finish"
+            ],
+            names[..6]
+        );
+        assert!(!names.contains(&"duration"));
+        assert!(!names.contains(&"preset"));
+        assert_eq!(
+            1,
+            names
+                .iter()
+                .filter(|name| **name == "color-pattern")
+                .count()
+        );
+        assert!(resolved.contains(&("color-spin".to_string(), Value::Int(-90))));
+
+        for explain in [Explain::Args, Explain::Toml] {
+            let explained = match explain {
+                Explain::Args => {
+                    let line = explain.format(&resolved);
+                    Args::try_parse_changed(line.split(' ').map(|arg| arg.trim_matches('\'')))
+                }
+                Explain::Toml => {
+                    let file = config_args(&explain.format(&resolved), &Args::command()).unwrap();
+                    Args::try_parse_changed(["wipe".to_string()].into_iter().chain(file))
+                }
+            }
+            .unwrap();
+            // The choices don't depend on the seed.
+            let rng = &mut ChaCha8Rng::seed_from_u64(9);
+            let char = explained.char_config(rng).unwrap();
+            let color = explained.color_config(rng).unwrap();
+            let pallet = explained.pallet(rng).unwrap();
+            let background = explained.background_config(&color, rng).unwrap();
+
+            assert_eq!(
+                resolved,
+                explained.resolved(&char, &color, &pallet, Some(&background))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_explain() {
+        let args = Args::try_parse_from(["wipe", "--dry-run"]).unwrap();
+        assert_eq!(Some(Explain::Args), args.explain);
+
+        let args = Args::try_parse_from(["wipe", "--explain", "toml"]).unwrap();
+        assert_eq!(Some(Explain::Toml), args.explain);
+    }
+
+    #[test]
+    fn config_args_overridden() {
+        let file = config_args("fps = 30\nduration = 500", &Args::command()).unwrap();
+        let mut cli = vec!["wipe".to_string(), "--fps".to_string(), "10".to_string()];
+        cli.splice(1..1, file);
+
+        let args = Args::try_parse_from(cli).unwrap();
+
+        assert_eq!(10, args.fps);
+        assert_eq!(500, args.duration);
+    }
+
+    #[test]
+    fn config_flag_overridden() {
+        let file = config_args("alternate-screen = true", &Args::command()).unwrap();
+        let mut cli = vec!["wipe".to_string(), "--alternate-screen=false".to_string()];
+        cli.splice(1..1, file);
+
+        assert!(!Args::try_parse_from(cli).unwrap().alternate_screen);
+        assert!(
+            Args::try_parse_from(["wipe", "--alternate-screen"])
+                .unwrap()
+                .alternate_screen
+        );
+        assert!(
+            !Args::try_parse_from(["wipe", "--print-seed", "false"])
+                .unwrap()
+                .print_seed
+        );
+    }
+
+    #[test]
+    fn cli_value_last() {
+        let cli: Vec<OsString> = [
+            "wipe",
+            "--config",
+            "a.toml",
+            "--fps",
+            "3",
+            "--config=b.toml",
+        ]
+        .iter()
+        .map(OsString::from)
+        .collect();
+
+        assert_eq!(Some("b.toml".to_string()), cli_value(&cli, "config"));
+        assert_eq!(Some("3".to_string()), cli_value(&cli, "fps"));
+        assert_eq!(None, cli_value(&cli, "preset"));
+    }
+
+    #[test]
+    fn cli_value_stops_at_separator() {
+        let cli: Vec<OsString> = ["wipe", "--", "--preset", "matrix"]
+            .iter()
+            .map(OsString::from)
+            .collect();
+
+        assert_eq!(None, cli_value(&cli, "preset"));
+    }
+
+    #[test]
+    fn config_args_cli_only() {
+        let command = Args::command();
+
+        for key in CLI_ONLY {
+            let content = format!("{} = \"1\"", key);
+
+            assert!(config_args(&content, &command).is_err());
+            assert!(presets(Some(&format!("[presets.calm]\n{}", content)), &command).is_err());
+        }
+    }
+
+    #[test]
+    fn presets_builtin() {
+        let presets = presets(None, &Args::command()).unwrap();
+        let names: Vec<_> = presets.iter().map(|preset| preset.name.as_str()).collect();
+
+        assert_eq!(vec!["matrix", "sunset", "minimal"], names);
+
+        for preset in presets {
+            let cli = ["wipe".to_string()].into_iter().chain(preset.args);
+
+            assert!(Args::try_parse_from(cli).is_ok());
+        }
+    }
+
+    #[test]
+    fn presets_from_config() {
+        let content = r#"
+            fps = 30
+
+            [presets.calm]
+            colors = "blue"
+            char-pattern = "wheel"
+
+            [presets.matrix]
+            colors = "dark-green"
+        "#;
+        let presets = presets(Some(content), &Args::command()).unwrap();
+        let find = |name| presets.iter().find(|preset| preset.name == name).unwrap();
+
+        assert_eq!(4, presets.len());
+        assert_eq!(
+            vec!["--char-pattern=wheel", "--colors=blue"],
+            find("calm").args
+        );
+        assert_eq!(vec!["--colors=dark-green"], find("matrix").args);
+    }
+
+    #[test]
+    fn presets_invalid() {
+        let command = Args::command();
+
+        assert!(presets(Some("presets = 1"), &command).is_err());
+        assert!(presets(Some("[presets]\ncalm = 1"), &command).is_err());
+        assert!(presets(Some("[presets.calm]\npreset = 'matrix'"), &command).is_err());
+        assert!(presets(Some("[presets.calm]\nbogus = 1"), &command).is_err());
+    }
+
+    #[test]
+    fn preset_args_checked() {
+        let content = "[presets.fast]\nfps = 'fast'";
+        let presets = presets(Some(content), &Args::command()).unwrap();
+        let err = format!("{:?}", preset_args(&presets, "fast").unwrap_err());
+
+        assert!(err.starts_with("Invalid preset 'fast': invalid value 'fast'"));
+        assert!(preset_args(&presets, "matrix").is_ok());
+        assert!(preset_args(&presets, "bogus").is_err());
+    }
+
+    #[test]
+    fn parse_pattern_weight_valid() {
+        assert_eq!(
+            Ok((PatternEnum::Circle, 3)),
+            parse_pattern_weight("circle=3")
+        );
+        assert_eq!(
+            Ok((PatternEnum::Line, 0)),
+            parse_pattern_weight(" Line = 0 ")
+        );
+    }
+
+    #[test]
+    fn parse_pattern_weight_invalid() {
+        assert!(parse_pattern_weight("circle").is_err());
+        assert!(parse_pattern_weight("square=1").is_err());
+        assert!(parse_pattern_weight("circle=-1").is_err());
+        assert!(parse_pattern_weight("circle=4294967295").is_err());
+    }
+
+    #[test]
+    fn parse_range_valid() {
+        assert_eq!(Ok(Range { min: 2, max: 6 }), parse_range("2-6"));
+        assert_eq!(Ok(Range { min: 3, max: 3 }), parse_range("3"));
+    }
+
+    #[test]
+    fn parse_range_invalid() {
+        assert!(parse_range("0-2").is_err());
+        assert!(parse_range("4-2").is_err());
+        assert!(parse_range("1-").is_err());
+        assert!(parse_range("a").is_err());
+    }
+
+    #[test]
+    fn pattern_weight() {
+        let args = Args::try_parse_from([
+            "wipe",
+            "--exclude-pattern",
+            "wheel,line",
+            "--pattern-weights",
+            "circle=3,line=2",
+        ])
+        .unwrap();
+
+        assert_eq!(3, args.pattern_weight(&PatternEnum::Circle));
+        assert_eq!(0, args.pattern_weight(&PatternEnum::Line));
+        assert_eq!(0, args.pattern_weight(&PatternEnum::Wheel));
+        assert_eq!(1, args.pattern_weight(&PatternEnum::Spiral));
+    }
+
+    #[test]
+    fn pallet_weight() {
+        let args = Args::try_parse_from([
+            "wipe",
+            "--colors-from",
+            "red,blue,gray",
+            "--exclude-colors",
+            "gray",
+        ])
+        .unwrap();
+
+        assert_eq!(1, args.pallet_weight(&PalletEnum::Red));
+        assert_eq!(0, args.pallet_weight(&PalletEnum::Gray));
+        assert_eq!(0, args.pallet_weight(&PalletEnum::Green));
+        assert_eq!(1, Args::default().pallet_weight(&PalletEnum::Green));
+    }
+
+    #[test]
+    fn choose_weighted() {
+        let args = Args::try_parse_from([
+            "wipe",
+            "--pattern-weights",
+            "circle=0,line=0,rhombus=0,wheel=0,spiral=0,noise=0",
+            "--colors-from",
+            "cyan",
+        ])
+        .unwrap();
+
+        for seed in 0..20 {
+            let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+
+            assert_eq!(
+                PatternEnum::Dissolve,
+                args.char_config(rng).unwrap().pattern
+            );
+            assert_eq!(
+                PatternEnum::Dissolve,
+                args.color_config(rng).unwrap().pattern
+            );
+            assert_eq!(Pallet::Preset(PalletEnum::Cyan), args.pallet(rng).unwrap());
+        }
+    }
+
+    #[test]
+    fn ranges() {
+        let args =
+            Args::try_parse_from(["wipe", "--segments-range", "5", "--shrink-range", "6"]).unwrap();
+        let rng = &mut ChaCha8Rng::seed_from_u64(0);
+
+        assert_abs_diff_eq!(5.0, args.char_config(rng).unwrap().segments);
+        assert_abs_diff_eq!(6.0, args.char_config(rng).unwrap().shrink);
+        assert_abs_diff_eq!(5.0, args.color_config(rng).unwrap().segments);
+    }
+
+    #[test]
+    fn validate() {
+        let args =
+            Args::try_parse_from(["wipe", "--exclude-pattern", "circle,line,wheel"]).unwrap();
+        assert!(args.validate().is_ok());
+
+        let args = Args::try_parse_from([
+            "wipe",
+            "--exclude-pattern",
+            "circle,line,rhombus,wheel",
+            "--pattern-weights",
+            "spiral=0,noise=0,dissolve=0",
+        ])
+        .unwrap();
+        assert!(args.validate().is_err());
+
+        let args =
+            Args::try_parse_from(["wipe", "--colors-from", "gray", "--exclude-colors", "gray"])
+                .unwrap();
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn validate_chosen() {
+        let excluded = "circle,line,rhombus,wheel,spiral,noise,dissolve";
+        let args = Args::try_parse_from([
+            "wipe",
+            "--exclude-pattern",
+            excluded,
+            "--char-pattern",
+            "circle",
+            "--color-pattern",
+            "line",
+            "--exclude-colors",
+            "gray",
+            "--colors-from",
+            "gray",
+            "--colors",
+            "red",
+        ])
+        .unwrap();
+        let rng = &mut ChaCha8Rng::seed_from_u64(1);
+
+        assert!(args.validate().is_ok());
+        assert!(args.char_config(rng).is_ok());
+        assert!(args.pallet(rng).is_ok());
+
+        let args = Args::try_parse_from([
+            "wipe",
+            "--exclude-pattern",
+            excluded,
+            "--char-pattern",
+            "circle",
+            "--color-pattern",
+            "line",
+            "--background-colors",
+            "red",
+        ])
+        .unwrap();
+
+        let color = args.color_config(rng).unwrap();
+
+        assert!(args.validate().is_err());
+        assert!(args.background_config(&color, rng).is_err());
+    }
+
+    #[test]
+    fn pattern_weights_max() {
+        let args =
+            Args::try_parse_from(["wipe", "--pattern-weights", "circle=65535,line=65535"]).unwrap();
+        let rng = &mut ChaCha8Rng::seed_from_u64(1);
+
+        assert!(args.validate().is_ok());
+        assert!(args.char_config(rng).is_ok());
+    }
+
     #[test]
     fn duration() {
         let args = Args {
@@ -323,7 +1817,7 @@ mod test {
             char_pattern: Some(PatternEnum::Line),
             ..Args::default()
         };
-        assert_eq!(PatternEnum::Line, args.char_config(rng).pattern);
+        assert_eq!(PatternEnum::Line, args.char_config(rng).unwrap().pattern);
     }
 
     #[test]
@@ -333,7 +1827,7 @@ mod test {
             char_invert: Some(false),
             ..Args::default()
         };
-        assert_eq!(false, args.char_config(rng).invert);
+        assert!(!args.char_config(rng).unwrap().invert);
     }
 
     #[test]
@@ -341,7 +1835,7 @@ mod test {
         let rng = &mut StepRng::new(1, 1);
         let args = Args::default();
 
-        assert_eq!(true, args.char_config(rng).shift);
+        assert!(args.char_config(rng).unwrap().shift);
     }
 
     #[test]
@@ -351,7 +1845,7 @@ mod test {
             char_swap: Some(true),
             ..Args::default()
         };
-        assert_eq!(true, args.char_config(rng).swap);
+        assert!(args.char_config(rng).unwrap().swap);
     }
 
     #[test]
@@ -361,7 +1855,7 @@ mod test {
             char_segments: Some(12),
             ..Args::default()
         };
-        assert_abs_diff_eq!(12.0, args.char_config(rng).segments);
+        assert_abs_diff_eq!(12.0, args.char_config(rng).unwrap().segments);
     }
 
     #[test]
@@ -371,7 +1865,63 @@ mod test {
             char_shrink: Some(42),
             ..Args::default()
         };
-        assert_abs_diff_eq!(42.0, args.char_config(rng).shrink);
+        assert_abs_diff_eq!(42.0, args.char_config(rng).unwrap().shrink);
+    }
+
+    #[test]
+    fn char_config_spiral() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            char_spiral_arms: Some(3),
+            char_spiral_turns: Some(5),
+            ..Args::default()
+        };
+        let config = args.char_config(rng).unwrap();
+
+        assert_abs_diff_eq!(3.0, config.arms);
+        assert_abs_diff_eq!(5.0, config.turns);
+    }
+
+    #[test]
+    fn char_config_noise() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            char_noise_seed: Some(1234),
+            char_noise_frequency: Some(6),
+            char_noise_octaves: Some(2),
+            char_dissolve_seed: Some(4321),
+            ..Args::default()
+        };
+        let config = args.char_config(rng).unwrap();
+
+        assert_eq!(1234, config.noise_seed);
+        assert_eq!(4321, config.dissolve_seed);
+        assert_abs_diff_eq!(6.0, config.frequency);
+        assert_eq!(2, config.octaves);
+    }
+
+    #[test]
+    fn char_config_angle() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            char_angle: 90,
+            ..Args::default()
+        };
+        assert_abs_diff_eq!(PI / 2.0, args.char_config(rng).unwrap().angle);
+    }
+
+    #[test]
+    fn char_config_rotation() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            char_rotation: 45,
+            char_spin: -360,
+            ..Args::default()
+        };
+        let config = args.char_config(rng).unwrap();
+
+        assert_abs_diff_eq!(PI / 4.0, config.rotation);
+        assert_abs_diff_eq!(-2.0 * PI, config.spin);
     }
 
     #[test]
@@ -381,7 +1931,7 @@ mod test {
             color_pattern: Some(PatternEnum::Circle),
             ..Args::default()
         };
-        assert_eq!(PatternEnum::Circle, args.color_config(rng).pattern);
+        assert_eq!(PatternEnum::Circle, args.color_config(rng).unwrap().pattern);
     }
 
     #[test]
@@ -391,7 +1941,7 @@ mod test {
             color_invert: Some(true),
             ..Args::default()
         };
-        assert_eq!(true, args.color_config(rng).invert);
+        assert!(args.color_config(rng).unwrap().invert);
     }
 
     #[test]
@@ -401,7 +1951,7 @@ mod test {
             color_shift: Some(false),
             ..Args::default()
         };
-        assert_eq!(false, args.color_config(rng).shift);
+        assert!(!args.color_config(rng).unwrap().shift);
     }
 
     #[test]
@@ -411,7 +1961,7 @@ mod test {
             color_swap: Some(true),
             ..Args::default()
         };
-        assert_eq!(true, args.color_config(rng).swap);
+        assert!(args.color_config(rng).unwrap().swap);
     }
 
     #[test]
@@ -422,7 +1972,7 @@ mod test {
             ..Args::default()
         };
 
-        assert_abs_diff_eq!(23.0, args.color_config(rng).segments);
+        assert_abs_diff_eq!(23.0, args.color_config(rng).unwrap().segments);
     }
 
     #[test]
@@ -430,7 +1980,108 @@ mod test {
         let rng = &mut StepRng::new(1, 1);
         let args = Args::default();
 
-        assert_abs_diff_eq!(1.0, args.color_config(rng).shrink);
+        assert_abs_diff_eq!(1.0, args.color_config(rng).unwrap().shrink);
+    }
+
+    #[test]
+    fn color_config_spiral() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            color_spiral_arms: Some(7),
+            color_spiral_turns: Some(2),
+            ..Args::default()
+        };
+        let config = args.color_config(rng).unwrap();
+
+        assert_abs_diff_eq!(7.0, config.arms);
+        assert_abs_diff_eq!(2.0, config.turns);
+    }
+
+    #[test]
+    fn color_config_noise() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            color_noise_seed: Some(99),
+            color_noise_frequency: Some(3),
+            color_noise_octaves: Some(5),
+            color_dissolve_seed: Some(77),
+            ..Args::default()
+        };
+        let config = args.color_config(rng).unwrap();
+
+        assert_eq!(99, config.noise_seed);
+        assert_eq!(77, config.dissolve_seed);
+        assert_abs_diff_eq!(3.0, config.frequency);
+        assert_eq!(5, config.octaves);
+    }
+
+    #[test]
+    fn color_config_angle() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            color_angle: 180,
+            ..Args::default()
+        };
+        assert_abs_diff_eq!(PI, args.color_config(rng).unwrap().angle);
+    }
+
+    #[test]
+    fn color_config_rotation() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            color_rotation: 90,
+            color_spin: 180,
+            ..Args::default()
+        };
+        let config = args.color_config(rng).unwrap();
+
+        assert_abs_diff_eq!(PI / 2.0, config.rotation);
+        assert_abs_diff_eq!(PI, config.spin);
+    }
+
+    #[test]
+    fn background_pallet_default_resets() {
+        assert_eq!(
+            vec![Reset],
+            Args::default()
+                .background_pallet()
+                .colors(ColorDepth::Truecolor, Interpolation::None)
+        );
+    }
+
+    #[test]
+    fn background_pallet() {
+        let args = Args {
+            background_colors: Some(Pallet::Custom(vec![Blue, Cyan])),
+            ..Args::default()
+        };
+        assert_eq!(
+            vec![Blue, Cyan],
+            args.background_pallet()
+                .colors(ColorDepth::Ansi16, Interpolation::None)
+        );
+    }
+
+    #[test]
+    fn background_config() {
+        let rng = &mut StepRng::new(1, 1);
+        let args = Args {
+            background_pattern: Some(PatternEnum::Rhombus),
+            color_pattern: Some(PatternEnum::Line),
+            color_shift: Some(true),
+            color_invert: Some(false),
+            color_segments: Some(3),
+            ..Args::default()
+        };
+        let color = args.color_config(rng).unwrap();
+        let config = args.background_config(&color, rng).unwrap();
+
+        assert_eq!(PatternEnum::Rhombus, config.pattern);
+        assert!(config.shift);
+        assert!(!config.invert);
+        assert_eq!(color.swap, config.swap);
+        assert_abs_diff_eq!(3.0, config.segments);
+        assert_abs_diff_eq!(1.0, config.shrink);
     }
 
     #[test]
@@ -443,6 +2094,15 @@ mod test {
                 swap: true,
                 segments: 3.0,
                 shrink: 2.0,
+                arms: 2.0,
+                turns: 3.0,
+                noise_seed: 5,
+                frequency: 2.0,
+                octaves: 3,
+                dissolve_seed: 6,
+                angle: 1.0,
+                rotation: 0.5,
+                spin: 2.0,
             };
             config
                 .create()
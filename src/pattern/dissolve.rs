@@ -0,0 +1,99 @@
+use crate::pattern::*;
+use crate::Vector;
+
+/// A factory for [Dissolve].
+///
+/// The seed is fixed on creation, so every frame uses the same thresholds.
+#[derive(derive_more::Constructor)]
+pub struct DissolveFactory {
+    seed: u64,
+}
+
+/// A [Pattern] with a random but stable level for every cell.
+#[derive(derive_more::Constructor)]
+pub struct Dissolve {
+    seed: u64,
+}
+
+impl PatternFactory for DissolveFactory {
+    fn create(&self, _: &Config) -> Box<dyn Pattern> {
+        Box::new(Dissolve::new(self.seed))
+    }
+}
+
+impl Pattern for Dissolve {
+    fn sample(&self, pos: Vector) -> f32 {
+        let x = pos.x.round() as i32 as u32 as u64;
+        let y = pos.y.round() as i32 as u32 as u64;
+        let hash = mix(self.seed ^ mix(x << 32 | y));
+
+        (hash >> 40) as f32 / (1 << 24) as f32
+    }
+}
+
+/// Scrambles the bits of a value (SplitMix64 finalizer).
+fn mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::*;
+
+    #[test]
+    fn sample_in_range() {
+        let pattern = DissolveFactory::new(3).create(&Config::default());
+
+        for x in 0..50 {
+            for y in 0..50 {
+                let sample = pattern.sample(Vector::from_terminal(x, y));
+
+                assert!((0.0..1.0).contains(&sample));
+            }
+        }
+    }
+
+    #[test]
+    fn sample_stable_across_frames() {
+        let factory = DissolveFactory::new(8);
+        let first = factory.create(&Config {
+            size: Vector::new(10.0, 10.0),
+            step: 0.2,
+        });
+        let second = factory.create(&Config {
+            size: Vector::new(30.0, 20.0),
+            step: 0.7,
+        });
+
+        for x in 0..10 {
+            let pos = Vector::from_terminal(x, 4);
+
+            assert_abs_diff_eq!(first.sample(pos), second.sample(pos));
+        }
+    }
+
+    #[test]
+    fn sample_cells_differ() {
+        let pattern = DissolveFactory::new(5).create(&Config::default());
+
+        assert_ne!(
+            pattern.sample(Vector::from_terminal(1, 2)),
+            pattern.sample(Vector::from_terminal(2, 1))
+        );
+    }
+
+    #[test]
+    fn sample_seed_changes_levels() {
+        let first = DissolveFactory::new(1).create(&Config::default());
+        let second = DissolveFactory::new(2).create(&Config::default());
+
+        assert_ne!(
+            first.sample(Vector::from_terminal(4, 4)),
+            second.sample(Vector::from_terminal(4, 4))
+        );
+    }
+}
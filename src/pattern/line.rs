@@ -3,29 +3,47 @@ use crate::Vector;
 
 /// A factory for [Line].
 #[derive(derive_more::Constructor)]
-pub struct LineFactory;
-/// A horizontal line [Pattern].
+pub struct LineFactory {
+    angle: f32,
+}
+/// A line [Pattern] sweeping along the given angle.
 pub struct Line {
-    width: f32,
+    direction: Vector,
+    start: f32,
+    length: f32,
 }
 
 impl PatternFactory for LineFactory {
     fn create(&self, config: &Config) -> Box<dyn Pattern> {
-        Box::new(Line::new(config))
+        Box::new(Line::new(config, self.angle))
     }
 }
 
 impl Line {
-    pub fn new(config: &Config) -> Self {
-        let width = config.size.x;
+    /// The angle is given in radians, where zero sweeps from left to right.
+    pub fn new(config: &Config, angle: f32) -> Self {
+        let direction = Vector::new(angle.cos(), angle.sin());
+        let corners = [
+            Vector::new(0.0, 0.0),
+            Vector::new(config.size.x, 0.0),
+            Vector::new(0.0, config.size.y),
+            config.size,
+        ];
+        let projections = corners.map(|corner| corner.dot(&direction));
+        let start = projections.into_iter().fold(f32::INFINITY, f32::min);
+        let end = projections.into_iter().fold(f32::NEG_INFINITY, f32::max);
 
-        Self { width }
+        Self {
+            direction,
+            start,
+            length: end - start,
+        }
     }
 }
 
 impl Pattern for Line {
     fn sample(&self, pos: Vector) -> f32 {
-        pos.x / self.width
+        (pos.dot(&self.direction) - self.start) / self.length
     }
 }
 
@@ -33,6 +51,7 @@ impl Pattern for Line {
 mod test {
     use super::*;
     use approx::*;
+    use std::f32::consts::PI;
 
     #[test]
     fn sample() {
@@ -40,11 +59,53 @@ mod test {
             size: Vector::new(20.0, 0.0),
             ..Config::default()
         };
-        let pattern = LineFactory::new().create(&config);
+        let pattern = LineFactory::new(0.0).create(&config);
 
         assert_abs_diff_eq!(0.0, pattern.sample(Vector::new(0.0, 4.0)), epsilon = 0.1);
         assert_abs_diff_eq!(0.4, pattern.sample(Vector::new(8.0, 8.0)), epsilon = 0.1);
         assert_abs_diff_eq!(0.8, pattern.sample(Vector::new(16.0, 7.0)), epsilon = 0.1);
         assert_abs_diff_eq!(1.0, pattern.sample(Vector::new(20.0, 3.0)), epsilon = 0.1);
     }
+
+    #[test]
+    fn sample_vertical() {
+        let config = Config {
+            size: Vector::new(20.0, 10.0),
+            ..Config::default()
+        };
+        let pattern = LineFactory::new(PI / 2.0).create(&config);
+
+        assert_abs_diff_eq!(0.0, pattern.sample(Vector::new(13.0, 0.0)), epsilon = 0.01);
+        assert_abs_diff_eq!(0.3, pattern.sample(Vector::new(2.0, 3.0)), epsilon = 0.01);
+        assert_abs_diff_eq!(1.0, pattern.sample(Vector::new(7.0, 10.0)), epsilon = 0.01);
+    }
+
+    #[test]
+    fn sample_reversed() {
+        let config = Config {
+            size: Vector::new(20.0, 10.0),
+            ..Config::default()
+        };
+        let pattern = LineFactory::new(PI).create(&config);
+
+        assert_abs_diff_eq!(1.0, pattern.sample(Vector::new(0.0, 4.0)), epsilon = 0.01);
+        assert_abs_diff_eq!(0.0, pattern.sample(Vector::new(20.0, 4.0)), epsilon = 0.01);
+    }
+
+    #[test]
+    fn sample_diagonal_corners() {
+        let config = Config {
+            size: Vector::new(20.0, 10.0),
+            ..Config::default()
+        };
+        let pattern = LineFactory::new(PI / 4.0).create(&config);
+
+        assert_abs_diff_eq!(0.0, pattern.sample(Vector::new(0.0, 0.0)), epsilon = 0.01);
+        assert_abs_diff_eq!(1.0, pattern.sample(Vector::new(20.0, 10.0)), epsilon = 0.01);
+        assert_abs_diff_eq!(
+            pattern.sample(Vector::new(10.0, 0.0)),
+            pattern.sample(Vector::new(0.0, 10.0)),
+            epsilon = 0.01
+        );
+    }
 }
@@ -1,13 +1,19 @@
 //! Contains all pattern traits and base patterns.
 
 mod circle;
+mod dissolve;
 mod line;
+mod noise;
 mod rhombus;
+mod spiral;
 mod wheel;
 
 pub use circle::*;
+pub use dissolve::*;
 pub use line::*;
+pub use noise::*;
 pub use rhombus::*;
+pub use spiral::*;
 pub use wheel::*;
 
 use crate::Vector;
@@ -23,14 +29,16 @@ pub struct Config {
 
 /// A factory to create a [Pattern].
 #[cfg_attr(test, mockall::automock)]
-pub trait PatternFactory {
+pub trait PatternFactory: Send + Sync {
     /// Creates a new [Pattern] with the given configuration.
     fn create(&self, config: &Config) -> Box<dyn Pattern>;
 }
 
 /// A pattern for an animation.
+///
+/// Patterns are sampled from multiple threads.
 #[cfg_attr(test, mockall::automock)]
-pub trait Pattern {
+pub trait Pattern: Send + Sync {
     /// Returns the level for a given coordinate.
     /// If it is a base pattern, the start position of the
     /// animation should by zero and the end position should be one.
@@ -49,12 +57,15 @@ pub trait SamplerFactory {
 
 /// A sampler for multiple values.
 #[cfg_attr(test, mockall::automock)]
-pub trait Sampler {
+pub trait Sampler: Sync {
     /// Returns the char level for a given position.
     fn char(&self, pos: Vector) -> f32;
 
     /// Returns the color level for a given position.
     fn color(&self, pos: Vector) -> f32;
+
+    /// Returns the background level for a given position if there is a background.
+    fn background(&self, pos: Vector) -> Option<f32>;
 }
 
 /// The implementation of [SamplerFactory].
@@ -62,6 +73,7 @@ pub trait Sampler {
 pub struct SamplerFactoryImpl {
     char: Box<dyn PatternFactory>,
     color: Box<dyn PatternFactory>,
+    background: Option<Box<dyn PatternFactory>>,
 }
 
 /// The implementation of [Sampler].
@@ -69,13 +81,20 @@ pub struct SamplerFactoryImpl {
 pub struct SamplerImpl {
     char: Box<dyn Pattern>,
     color: Box<dyn Pattern>,
+    background: Option<Box<dyn Pattern>>,
 }
 
 impl SamplerFactory for SamplerFactoryImpl {
     type Sampler = SamplerImpl;
 
     fn create(&self, config: &Config) -> Self::Sampler {
-        SamplerImpl::new(self.char.create(config), self.color.create(config))
+        SamplerImpl::new(
+            self.char.create(config),
+            self.color.create(config),
+            self.background
+                .as_ref()
+                .map(|background| background.create(config)),
+        )
     }
 }
 
@@ -87,6 +106,12 @@ impl Sampler for SamplerImpl {
     fn color(&self, pos: Vector) -> f32 {
         self.color.sample(pos)
     }
+
+    fn background(&self, pos: Vector) -> Option<f32> {
+        self.background
+            .as_ref()
+            .map(|background| background.sample(pos))
+    }
 }
 
 #[cfg(test)]
@@ -104,7 +129,7 @@ mod test {
             .with(eq(Vector::new(2.0, 5.0)))
             .return_const(2.5);
 
-        let sampler = SamplerImpl::new(Box::new(char), Box::new(color));
+        let sampler = SamplerImpl::new(Box::new(char), Box::new(color), None);
 
         assert_abs_diff_eq!(2.5, sampler.char(Vector::new(2.0, 5.0)));
     }
@@ -119,7 +144,7 @@ mod test {
             .with(eq(Vector::new(4.0, 2.0)))
             .return_const(3.2);
 
-        let sampler = SamplerImpl::new(Box::new(char), Box::new(color));
+        let sampler = SamplerImpl::new(Box::new(char), Box::new(color), None);
 
         assert_abs_diff_eq!(3.2, sampler.color(Vector::new(4.0, 2.0)));
     }
@@ -148,10 +173,59 @@ mod test {
                 Box::new(sampler)
             });
 
-        let factory = SamplerFactoryImpl::new(Box::new(char), Box::new(color));
+        let factory = SamplerFactoryImpl::new(Box::new(char), Box::new(color), None);
         let sampler = factory.create(&config);
 
         assert_abs_diff_eq!(3.0, sampler.char(Vector::default()));
         assert_abs_diff_eq!(5.0, sampler.color(Vector::default()));
+        assert_eq!(None, sampler.background(Vector::default()));
+    }
+
+    #[test]
+    fn background() {
+        let char = MockPattern::new();
+        let color = MockPattern::new();
+        let mut background = MockPattern::new();
+
+        background
+            .expect_sample()
+            .with(eq(Vector::new(1.0, 7.0)))
+            .return_const(0.9);
+
+        let sampler = SamplerImpl::new(Box::new(char), Box::new(color), Some(Box::new(background)));
+
+        assert_eq!(Some(0.9), sampler.background(Vector::new(1.0, 7.0)));
+    }
+
+    #[test]
+    fn factory_background() {
+        let mut char = MockPatternFactory::new();
+        let mut color = MockPatternFactory::new();
+        let mut background = MockPatternFactory::new();
+        let config = Config {
+            size: Vector::new(4.0, 1.0),
+            step: 0.2,
+        };
+
+        char.expect_create()
+            .returning(|_| Box::new(MockPattern::new()));
+        color
+            .expect_create()
+            .returning(|_| Box::new(MockPattern::new()));
+        background
+            .expect_create()
+            .with(eq(config))
+            .once()
+            .returning(|_| {
+                let mut sampler = MockPattern::new();
+                sampler.expect_sample().return_const(0.4);
+                Box::new(sampler)
+            });
+
+        let factory =
+            SamplerFactoryImpl::new(Box::new(char), Box::new(color), Some(Box::new(background)));
+        let sampler = factory.create(&config);
+
+        assert_eq!(Some(0.4), sampler.background(Vector::default()));
     }
 }
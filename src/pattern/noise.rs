@@ -0,0 +1,279 @@
+use crate::pattern::*;
+use crate::Vector;
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use std::sync::Mutex;
+
+/// The gradients used for the noise.
+const GRADIENTS: [(f32, f32); 8] = [
+    (1.0, 1.0),
+    (-1.0, 1.0),
+    (1.0, -1.0),
+    (-1.0, -1.0),
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+];
+
+/// A factory for [Noise].
+///
+/// The permutation table is generated once from the seed,
+/// so every frame samples the same noise.
+/// The range of the noise is cached for the last terminal size.
+pub struct NoiseFactory {
+    permutation: [u8; 512],
+    frequency: f32,
+    octaves: u8,
+    range: Mutex<Option<(Vector, f32, f32)>>,
+}
+
+/// A gradient noise [Pattern].
+pub struct Noise {
+    permutation: [u8; 512],
+    scale: f32,
+    octaves: u8,
+    min: f32,
+    max: f32,
+}
+
+impl NoiseFactory {
+    pub fn new(seed: u64, frequency: f32, octaves: u8) -> Self {
+        let mut table: Vec<u8> = (0..=255).collect();
+        let mut permutation = [0; 512];
+
+        table.shuffle(&mut ChaCha8Rng::seed_from_u64(seed));
+
+        for (i, value) in permutation.iter_mut().enumerate() {
+            *value = table[i % 256];
+        }
+        Self {
+            permutation,
+            frequency,
+            octaves,
+            range: Mutex::new(None),
+        }
+    }
+}
+
+impl PatternFactory for NoiseFactory {
+    fn create(&self, config: &Config) -> Box<dyn Pattern> {
+        let mut noise = Noise::new(config, self.permutation, self.frequency, self.octaves);
+        let mut range = self.range.lock().unwrap();
+
+        let (min, max) = match *range {
+            Some((size, min, max)) if size == config.size => (min, max),
+            _ => {
+                let (min, max) = noise.scan(config.size);
+                *range = Some((config.size, min, max));
+                (min, max)
+            }
+        };
+        if min < max {
+            noise.min = min;
+            noise.max = max;
+        }
+        Box::new(noise)
+    }
+}
+
+impl Noise {
+    pub fn new(config: &Config, permutation: [u8; 512], frequency: f32, octaves: u8) -> Self {
+        Self {
+            permutation,
+            scale: frequency / config.size.len(),
+            octaves,
+            min: -1.0,
+            max: 1.0,
+        }
+    }
+
+    /// Returns the lowest and highest value of the noise around the terminal.
+    ///
+    /// The noise rarely gets close to its theoretical bounds,
+    /// so it is stretched to this range.
+    /// The scan covers every rotation of the terminal
+    /// and the half cells of the finer render modes.
+    fn scan(&self, size: Vector) -> (f32, f32) {
+        let radius = size.len() / 2.0;
+        let start = size.center() - Vector::new(radius, radius);
+        let steps = (radius * 4.0).ceil() as u32;
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+
+        for x in 0..=steps {
+            for y in 0..=steps {
+                let pos = start + Vector::new(x as f32 * 0.5, y as f32 * 0.5);
+                let value = self.fractal(pos);
+
+                min = min.min(value);
+                max = max.max(value);
+            }
+        }
+        (min, max)
+    }
+
+    /// Returns the sum of all octaves in the range of minus one to one.
+    fn fractal(&self, pos: Vector) -> f32 {
+        let mut x = pos.x * self.scale;
+        let mut y = pos.y * self.scale;
+        let mut amplitude = 1.0;
+        let mut total = 0.0;
+        let mut sum = 0.0;
+
+        for _ in 0..self.octaves {
+            sum += self.octave(x, y) * amplitude;
+            total += amplitude;
+            amplitude /= 2.0;
+            x *= 2.0;
+            y *= 2.0;
+        }
+        sum / total
+    }
+
+    /// Returns the noise for a single octave in the range of minus one to one.
+    fn octave(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (dx, dy) = (x - x0, y - y0);
+        let (xi, yi) = ((x0 as i32 & 255) as usize, (y0 as i32 & 255) as usize);
+        let (u, v) = (fade(dx), fade(dy));
+
+        let top = lerp(
+            self.gradient(xi, yi, dx, dy),
+            self.gradient(xi + 1, yi, dx - 1.0, dy),
+            u,
+        );
+        let bottom = lerp(
+            self.gradient(xi, yi + 1, dx, dy - 1.0),
+            self.gradient(xi + 1, yi + 1, dx - 1.0, dy - 1.0),
+            u,
+        );
+        lerp(top, bottom, v)
+    }
+
+    /// Returns the dot product of the lattice gradient and the offset.
+    fn gradient(&self, xi: usize, yi: usize, dx: f32, dy: f32) -> f32 {
+        let hash = self.permutation[self.permutation[xi] as usize + yi];
+        let (gx, gy) = GRADIENTS[hash as usize % GRADIENTS.len()];
+
+        gx * dx + gy * dy
+    }
+}
+
+impl Pattern for Noise {
+    fn sample(&self, pos: Vector) -> f32 {
+        let value = self.fractal(pos);
+
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+/// Smooths the interpolation between two lattice points.
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Linearly interpolates between two values.
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::*;
+
+    #[test]
+    fn sample_in_range() {
+        let config = Config {
+            size: Vector::new(40.0, 30.0),
+            ..Config::default()
+        };
+        let pattern = NoiseFactory::new(7, 4.0, 3).create(&config);
+
+        for x in 0..40 {
+            for y in 0..30 {
+                let sample = pattern.sample(Vector::new(x as f32, y as f32));
+
+                assert!((0.0..=1.0).contains(&sample));
+            }
+        }
+    }
+
+    #[test]
+    fn sample_reaches_both_ends() {
+        let config = Config {
+            size: Vector::new(40.0, 30.0),
+            ..Config::default()
+        };
+
+        for octaves in 1..=4 {
+            let pattern = NoiseFactory::new(11, 3.0, octaves).create(&config);
+            // The positions of a rotated terminal reach out to the circle around it.
+            let samples: Vec<_> = (-10..=90)
+                .flat_map(|x| (-20..=80).map(move |y| Vector::new(x as f32, y as f32)))
+                .map(|pos| Vector::new(pos.x * 0.5, pos.y * 0.5))
+                .map(|pos| pattern.sample(pos))
+                .collect();
+            let min = samples.iter().copied().fold(f32::MAX, f32::min);
+            let max = samples.iter().copied().fold(f32::MIN, f32::max);
+
+            assert_abs_diff_eq!(0.0, min);
+            assert_abs_diff_eq!(1.0, max);
+        }
+    }
+
+    #[test]
+    fn create_caches_range() {
+        let factory = NoiseFactory::new(5, 2.0, 2);
+        let size = Vector::new(20.0, 10.0);
+
+        factory.create(&Config { size, step: 0.1 });
+        let first = *factory.range.lock().unwrap();
+        factory.create(&Config { size, step: 0.9 });
+
+        assert_eq!(first, *factory.range.lock().unwrap());
+        assert_eq!(Some(size), first.map(|(size, _, _)| size));
+
+        let size = Vector::new(30.0, 10.0);
+        factory.create(&Config { size, step: 0.1 });
+
+        assert_eq!(
+            Some(size),
+            factory.range.lock().unwrap().map(|(size, _, _)| size)
+        );
+    }
+
+    #[test]
+    fn sample_stable_across_frames() {
+        let factory = NoiseFactory::new(42, 3.0, 2);
+        let first = factory.create(&Config {
+            size: Vector::new(20.0, 10.0),
+            step: 0.1,
+        });
+        let second = factory.create(&Config {
+            size: Vector::new(20.0, 10.0),
+            step: 0.9,
+        });
+
+        for x in 0..20 {
+            let pos = Vector::new(x as f32, 3.5);
+
+            assert_abs_diff_eq!(first.sample(pos), second.sample(pos));
+        }
+    }
+
+    #[test]
+    fn sample_seed_changes_noise() {
+        let config = Config {
+            size: Vector::new(20.0, 10.0),
+            ..Config::default()
+        };
+        let first = NoiseFactory::new(1, 3.0, 2).create(&config);
+        let second = NoiseFactory::new(2, 3.0, 2).create(&config);
+
+        assert!((0..20)
+            .map(|x| Vector::new(x as f32 + 0.5, 3.5))
+            .any(|pos| first.sample(pos) != second.sample(pos)));
+    }
+}
@@ -0,0 +1,81 @@
+use crate::pattern::*;
+use crate::Vector;
+use std::f32::consts::PI;
+
+/// A factory for [Spiral].
+#[derive(derive_more::Constructor)]
+pub struct SpiralFactory {
+    arms: f32,
+    turns: f32,
+}
+/// A spiral [Pattern] with `n` arms.
+pub struct Spiral {
+    center: Vector,
+    radius: f32,
+    arms: f32,
+    turns: f32,
+}
+
+impl PatternFactory for SpiralFactory {
+    fn create(&self, config: &Config) -> Box<dyn Pattern> {
+        Box::new(Spiral::new(config, self.arms, self.turns))
+    }
+}
+
+impl Spiral {
+    pub fn new(config: &Config, arms: f32, turns: f32) -> Self {
+        let center = config.size.center();
+        let radius = center.len();
+
+        Self {
+            center,
+            radius,
+            arms,
+            turns,
+        }
+    }
+}
+
+impl Pattern for Spiral {
+    fn sample(&self, pos: Vector) -> f32 {
+        let pos = pos - self.center;
+        let distance = pos.len() / self.radius;
+        let angle = (pos.angle() + PI) / PI / 2.0 * self.arms % 1.0;
+
+        (distance * self.turns + angle) / (self.turns + 1.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::*;
+
+    #[test]
+    fn sample() {
+        let config = Config {
+            size: Vector::new(10.0, 20.0),
+            ..Config::default()
+        };
+        let pattern = SpiralFactory::new(1.0, 1.0).create(&config);
+
+        assert_abs_diff_eq!(0.25, pattern.sample(Vector::new(5.0, 10.0)), epsilon = 0.1);
+        assert_abs_diff_eq!(0.47, pattern.sample(Vector::new(10.0, 10.0)), epsilon = 0.1);
+        assert_abs_diff_eq!(0.82, pattern.sample(Vector::new(5.0, 20.0)), epsilon = 0.1);
+    }
+
+    #[test]
+    fn sample_arms_symmetric() {
+        let config = Config {
+            size: Vector::new(10.0, 20.0),
+            ..Config::default()
+        };
+        let pattern = SpiralFactory::new(2.0, 3.0).create(&config);
+
+        assert_abs_diff_eq!(
+            pattern.sample(Vector::new(0.0, 10.0)),
+            pattern.sample(Vector::new(10.0, 10.0)),
+            epsilon = 0.01
+        );
+    }
+}
@@ -3,10 +3,12 @@ use crate::Terminal;
 use crossterm::cursor::*;
 use crossterm::style::*;
 use crossterm::terminal::*;
+use crossterm::Command;
 
 /// A trait for performance optimized terminal output.
 ///
 /// All commands are queue and have to be executed using [Printer::flush].
+/// Consecutive chars with the same style are printed as a single string.
 #[cfg_attr(test, mockall::automock)]
 pub trait Printer {
     /// Shows the cursor if it isn't visible.
@@ -19,42 +21,99 @@ pub trait Printer {
     fn print(&mut self, char: char) -> Result<(), Error>;
     /// Moves the cursor to the specified position if it isn't there already.
     fn move_to(&mut self, x: u16, y: u16) -> Result<(), Error>;
+    /// Moves the cursor to the start of the next line and scrolls on the last line.
+    fn new_line(&mut self) -> Result<(), Error>;
     /// Returns the size of the terminal.
     fn size(&self) -> Result<(u16, u16), Error>;
+    /// Returns the position of the cursor.
+    fn position(&self) -> (u16, u16);
     /// Sets the foreground color of the terminal.
     fn set_foreground(&mut self, color: Color) -> Result<(), Error>;
+    /// Sets the background color of the terminal.
+    fn set_background(&mut self, color: Color) -> Result<(), Error>;
+    /// Sets the text attributes of the terminal.
+    fn set_attributes(&mut self, attributes: Attributes) -> Result<(), Error>;
     /// Clears the terminal content.
     fn clear(&mut self) -> Result<(), Error>;
+    /// Clears the visible terminal content and moves the cursor to the top left.
+    fn clear_screen(&mut self) -> Result<(), Error>;
+    /// Switches to the alternate screen.
+    fn enter_alternate_screen(&mut self) -> Result<(), Error>;
+    /// Switches back to the main screen.
+    fn leave_alternate_screen(&mut self) -> Result<(), Error>;
+    /// Begins a synchronized update if enabled.
+    fn begin_update(&mut self) -> Result<(), Error>;
+    /// Ends a synchronized update if enabled.
+    fn end_update(&mut self) -> Result<(), Error>;
     /// Flushes all queue commands.
     fn flush(&mut self) -> Result<(), Error>;
 }
 
+/// Whether frames are wrapped in synchronized updates.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug, Default)]
+pub enum SyncOutput {
+    /// Use synchronized updates if the terminal reports support for them.
+    #[default]
+    Auto,
+    /// Always use synchronized updates.
+    On,
+    /// Never use synchronized updates.
+    Off,
+}
+
 /// The implementation of [Printer].
 pub struct PrinterImpl<T> {
     term: T,
     position: (u16, u16),
     cursor: Option<bool>,
     foreground: Option<Color>,
+    background: Option<Color>,
+    attributes: Option<Attributes>,
+    synchronized: bool,
+    run: String,
 }
 
 impl<T: Terminal> PrinterImpl<T> {
-    pub fn new(term: T) -> Result<Self, Error> {
+    pub fn new(term: T, sync: SyncOutput) -> Result<Self, Error> {
         let position = term.position()?;
+        let synchronized = match sync {
+            SyncOutput::Auto => term.supports_synchronized_update(),
+            SyncOutput::On => true,
+            SyncOutput::Off => false,
+        };
 
         Ok(Self {
             term,
             position,
             cursor: None,
             foreground: None,
+            background: None,
+            attributes: None,
+            synchronized,
+            run: String::new(),
         })
     }
+
+    /// Queues the pending run of chars as a single print.
+    fn queue_run(&mut self) -> Result<(), Error> {
+        if !self.run.is_empty() {
+            self.term.queue(Print(std::mem::take(&mut self.run)))?;
+        }
+        Ok(())
+    }
+
+    /// Queues a command after the pending run of chars.
+    fn queue(&mut self, cmd: impl Command + 'static) -> Result<(), Error> {
+        self.queue_run()?;
+        self.term.queue(cmd)
+    }
 }
 
 impl<T: Terminal> Printer for PrinterImpl<T> {
     fn show_cursor(&mut self) -> Result<(), Error> {
         if self.cursor != Some(true) {
             self.cursor = Some(true);
-            self.term.queue(Show)?;
+            self.queue(Show)?;
         }
         Ok(())
     }
@@ -62,7 +121,7 @@ impl<T: Terminal> Printer for PrinterImpl<T> {
     fn hide_cursor(&mut self) -> Result<(), Error> {
         if self.cursor != Some(false) {
             self.cursor = Some(false);
-            self.term.queue(Hide)?;
+            self.queue(Hide)?;
         }
         Ok(())
     }
@@ -72,36 +131,101 @@ impl<T: Terminal> Printer for PrinterImpl<T> {
             return Err("Special chars can't be printed.".into());
         }
         self.position.0 += 1;
-        self.term.queue(Print(char))?;
+        self.run.push(char);
         Ok(())
     }
 
     fn move_to(&mut self, x: u16, y: u16) -> Result<(), Error> {
         if self.position != (x, y) {
             self.position = (x, y);
-            self.term.queue(MoveTo(x, y))?;
+            self.queue(MoveTo(x, y))?;
         }
         Ok(())
     }
 
+    fn new_line(&mut self) -> Result<(), Error> {
+        let (_, height) = self.term.size()?;
+
+        self.position = (0, (self.position.1 + 1).min(height.saturating_sub(1)));
+        self.queue(Print("\r\n"))
+    }
+
     fn size(&self) -> Result<(u16, u16), Error> {
         self.term.size()
     }
 
+    fn position(&self) -> (u16, u16) {
+        self.position
+    }
+
     fn set_foreground(&mut self, color: Color) -> Result<(), Error> {
         if self.foreground != Some(color) {
             self.foreground = Some(color);
-            self.term.queue(SetForegroundColor(color))?;
+            self.queue(SetForegroundColor(color))?;
+        }
+        Ok(())
+    }
+
+    fn set_background(&mut self, color: Color) -> Result<(), Error> {
+        if self.background != Some(color) {
+            self.background = Some(color);
+            self.queue(SetBackgroundColor(color))?;
+        }
+        Ok(())
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> Result<(), Error> {
+        if self.attributes != Some(attributes) {
+            // Resetting the attributes resets the colors too.
+            self.attributes = Some(attributes);
+            self.foreground = None;
+            self.background = None;
+            self.queue(SetAttribute(Attribute::Reset))?;
+
+            if !attributes.is_empty() {
+                self.queue(SetAttributes(attributes))?;
+            }
         }
         Ok(())
     }
 
     fn clear(&mut self) -> Result<(), Error> {
-        self.term.queue(Clear(ClearType::Purge))?;
+        self.queue(Clear(ClearType::Purge))?;
+        Ok(())
+    }
+
+    fn clear_screen(&mut self) -> Result<(), Error> {
+        // The cursor position is unknown after the terminal reflowed.
+        self.position = (0, 0);
+        self.queue(Clear(ClearType::All))?;
+        self.queue(MoveTo(0, 0))?;
+        Ok(())
+    }
+
+    fn enter_alternate_screen(&mut self) -> Result<(), Error> {
+        self.queue(EnterAlternateScreen)
+    }
+
+    fn leave_alternate_screen(&mut self) -> Result<(), Error> {
+        self.queue(LeaveAlternateScreen)
+    }
+
+    fn begin_update(&mut self) -> Result<(), Error> {
+        if self.synchronized {
+            self.queue(BeginSynchronizedUpdate)?;
+        }
+        Ok(())
+    }
+
+    fn end_update(&mut self) -> Result<(), Error> {
+        if self.synchronized {
+            self.queue(EndSynchronizedUpdate)?;
+        }
         Ok(())
     }
 
     fn flush(&mut self) -> Result<(), Error> {
+        self.queue_run()?;
         self.term.flush()?;
         Ok(())
     }
@@ -112,6 +236,41 @@ mod test {
     use super::*;
     use crate::MockTerminal;
     use mockall::predicate::eq;
+    use mockall::Sequence;
+
+    /// A [Terminal] counting the queued commands and their bytes.
+    #[derive(Default)]
+    struct ByteCounter {
+        writes: usize,
+        bytes: usize,
+    }
+
+    impl Terminal for ByteCounter {
+        fn queue<T: 'static + Command>(&mut self, cmd: T) -> Result<(), Error> {
+            let mut ansi = String::new();
+            cmd.write_ansi(&mut ansi).unwrap();
+
+            self.writes += 1;
+            self.bytes += ansi.len();
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<(), Error> {
+            Ok(())
+        }
+
+        fn size(&self) -> Result<(u16, u16), Error> {
+            Ok((8, 2))
+        }
+
+        fn position(&self) -> Result<(u16, u16), Error> {
+            Ok((0, 0))
+        }
+
+        fn supports_synchronized_update(&self) -> bool {
+            false
+        }
+    }
 
     #[test]
     fn show_cursor() {
@@ -122,7 +281,10 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().show_cursor().unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .show_cursor()
+            .unwrap();
     }
 
     #[test]
@@ -134,7 +296,7 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.show_cursor().unwrap();
         printer.show_cursor().unwrap();
@@ -150,7 +312,7 @@ mod test {
             .returning(|_| Ok(()));
         mock.expect_queue().with(eq(Hide)).returning(|_| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.hide_cursor().unwrap();
         printer.show_cursor().unwrap();
@@ -165,7 +327,10 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().hide_cursor().unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .hide_cursor()
+            .unwrap();
     }
 
     #[test]
@@ -177,7 +342,7 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.hide_cursor().unwrap();
         printer.hide_cursor().unwrap();
@@ -193,7 +358,7 @@ mod test {
             .returning(|_| Ok(()));
         mock.expect_queue().with(eq(Hide)).returning(|_| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.show_cursor().unwrap();
         printer.hide_cursor().unwrap();
@@ -208,7 +373,7 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        PrinterImpl::new(mock)
+        PrinterImpl::new(mock, SyncOutput::Off)
             .unwrap()
             .set_foreground(Color::Blue)
             .unwrap();
@@ -222,7 +387,7 @@ mod test {
             .once()
             .returning(|_: SetForegroundColor| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.set_foreground(Color::Red).unwrap();
         printer.set_foreground(Color::Red).unwrap();
@@ -236,34 +401,154 @@ mod test {
             .times(3)
             .returning(|_: SetForegroundColor| Ok(()));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.set_foreground(Color::Red).unwrap();
         printer.set_foreground(Color::Blue).unwrap();
         printer.set_foreground(Color::Red).unwrap();
     }
 
+    #[test]
+    fn set_background() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(SetBackgroundColor(Color::Green)))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .set_background(Color::Green)
+            .unwrap();
+    }
+
+    #[test]
+    fn set_background_twice_queues_once() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .once()
+            .returning(|_: SetBackgroundColor| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.set_background(Color::Red).unwrap();
+        printer.set_background(Color::Red).unwrap();
+    }
+
+    #[test]
+    fn set_background_different_color_queues() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .times(3)
+            .returning(|_: SetBackgroundColor| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.set_background(Color::Red).unwrap();
+        printer.set_background(Color::Blue).unwrap();
+        printer.set_background(Color::Red).unwrap();
+    }
+
+    #[test]
+    fn set_attributes() {
+        let mut mock = MockTerminal::new();
+        let attributes = Attributes::from(Attribute::Bold);
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(SetAttribute(Attribute::Reset)))
+            .once()
+            .returning(|_| Ok(()));
+        mock.expect_queue()
+            .with(eq(SetAttributes(attributes)))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .set_attributes(attributes)
+            .unwrap();
+    }
+
+    #[test]
+    fn set_attributes_empty_only_resets() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(SetAttribute(Attribute::Reset)))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .set_attributes(Attributes::default())
+            .unwrap();
+    }
+
+    #[test]
+    fn set_attributes_twice_queues_once() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .once()
+            .returning(|_: SetAttribute| Ok(()));
+        mock.expect_queue()
+            .once()
+            .returning(|_: SetAttributes| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.set_attributes(Attribute::Dim.into()).unwrap();
+        printer.set_attributes(Attribute::Dim.into()).unwrap();
+    }
+
+    #[test]
+    fn set_attributes_requeues_colors() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue().returning(|_: SetAttribute| Ok(()));
+        mock.expect_queue().returning(|_: SetAttributes| Ok(()));
+        mock.expect_queue()
+            .times(2)
+            .returning(|_: SetForegroundColor| Ok(()));
+        mock.expect_queue()
+            .times(2)
+            .returning(|_: SetBackgroundColor| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.set_foreground(Color::Red).unwrap();
+        printer.set_background(Color::Blue).unwrap();
+        printer.set_attributes(Attribute::Bold.into()).unwrap();
+        printer.set_foreground(Color::Red).unwrap();
+        printer.set_background(Color::Blue).unwrap();
+    }
+
     #[test]
     fn print() {
         let mut mock = MockTerminal::new();
         mock.expect_position().returning(|| Ok((0, 0)));
         mock.expect_queue()
-            .with(eq(Print('R')))
+            .with(eq(Print("R".to_string())))
             .once()
             .returning(|_| Ok(()));
+        mock.expect_flush().returning(|| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().print('R').unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.print('R').unwrap();
+        printer.flush().unwrap();
     }
 
     #[test]
     fn print_moves_cursor() {
         let mut mock = MockTerminal::new();
         mock.expect_position().returning(|| Ok((2, 4)));
-        mock.expect_queue()
-            .times(3)
-            .returning(|_: Print<char>| Ok(()));
+        mock.expect_queue::<MoveTo>().never();
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         printer.print('A').unwrap();
         printer.print('B').unwrap();
@@ -271,12 +556,85 @@ mod test {
         printer.move_to(5, 4).unwrap();
     }
 
+    #[test]
+    fn print_run_queues_once() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(Print("ABC".to_string())))
+            .once()
+            .returning(|_| Ok(()));
+        mock.expect_flush().returning(|| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.print('A').unwrap();
+        printer.print('B').unwrap();
+        printer.move_to(2, 0).unwrap();
+        printer.print('C').unwrap();
+        printer.flush().unwrap();
+    }
+
+    #[test]
+    fn print_run_ends_on_command() {
+        let mut mock = MockTerminal::new();
+        let seq = &mut Sequence::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(Print("AB".to_string())))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        mock.expect_queue()
+            .with(eq(SetForegroundColor(Color::Red)))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        mock.expect_queue()
+            .with(eq(Print("C".to_string())))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        mock.expect_flush().returning(|| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.print('A').unwrap();
+        printer.print('B').unwrap();
+        printer.set_foreground(Color::Red).unwrap();
+        printer.print('C').unwrap();
+        printer.flush().unwrap();
+    }
+
+    #[test]
+    fn print_run_reduces_writes() {
+        let mut printer = PrinterImpl::new(ByteCounter::default(), SyncOutput::Off).unwrap();
+
+        for y in 0..2 {
+            printer.move_to(0, y).unwrap();
+            printer.set_foreground(Color::Red).unwrap();
+            for _ in 0..4 {
+                printer.print('#').unwrap();
+            }
+            printer.set_foreground(Color::Blue).unwrap();
+            for _ in 0..4 {
+                printer.print('.').unwrap();
+            }
+        }
+        printer.flush().unwrap();
+
+        // One write per command and run instead of one per char.
+        assert_eq!(9, printer.term.writes);
+        // "\x1B[2;1H", twice "\x1B[38;5;9m" and "\x1B[38;5;12m" and the chars.
+        assert_eq!(6 + 2 * (9 + 10) + 16, printer.term.bytes);
+    }
+
     #[test]
     fn print_special_char_fails() {
         let mut mock = MockTerminal::new();
         mock.expect_position().returning(|| Ok((2, 4)));
 
-        let mut printer = PrinterImpl::new(mock).unwrap();
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
 
         assert!(printer.print('\u{0}').is_err());
         assert!(printer.print('\u{1F}').is_err());
@@ -292,7 +650,10 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().move_to(5, 4).unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .move_to(5, 4)
+            .unwrap();
     }
 
     #[test]
@@ -300,7 +661,42 @@ mod test {
         let mut mock = MockTerminal::new();
         mock.expect_position().returning(|| Ok((3, 13)));
 
-        PrinterImpl::new(mock).unwrap().move_to(3, 13).unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .move_to(3, 13)
+            .unwrap();
+    }
+
+    #[test]
+    fn position() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((2, 4)));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        assert_eq!((2, 4), printer.position());
+        printer.print('A').unwrap();
+        assert_eq!((3, 4), printer.position());
+    }
+
+    #[test]
+    fn new_line() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((5, 1)));
+        mock.expect_size().returning(|| Ok((10, 3)));
+        mock.expect_queue()
+            .with(eq(Print("\r\n")))
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.new_line().unwrap();
+        assert_eq!((0, 2), printer.position());
+
+        // The terminal scrolls on the last line.
+        printer.new_line().unwrap();
+        assert_eq!((0, 2), printer.position());
     }
 
     #[test]
@@ -309,7 +705,13 @@ mod test {
         mock.expect_position().returning(|| Ok((0, 0)));
         mock.expect_size().returning(|| Ok((14, 76)));
 
-        assert_eq!((14, 76), PrinterImpl::new(mock).unwrap().size().unwrap());
+        assert_eq!(
+            (14, 76),
+            PrinterImpl::new(mock, SyncOutput::Off)
+                .unwrap()
+                .size()
+                .unwrap()
+        );
     }
 
     #[test]
@@ -321,7 +723,45 @@ mod test {
             .once()
             .returning(|_| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().clear().unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .clear()
+            .unwrap();
+    }
+
+    #[test]
+    fn clear_screen() {
+        let mut mock = MockTerminal::new();
+        let seq = &mut Sequence::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(Clear(ClearType::All)))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        mock.expect_queue()
+            .with(eq(MoveTo(0, 0)))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .clear_screen()
+            .unwrap();
+    }
+
+    #[test]
+    fn clear_screen_resets_position() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((4, 2)));
+        mock.expect_queue::<Clear>().returning(|_| Ok(()));
+        mock.expect_queue::<MoveTo>().once().returning(|_| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.clear_screen().unwrap();
+        printer.move_to(0, 0).unwrap();
     }
 
     #[test]
@@ -330,6 +770,100 @@ mod test {
         mock.expect_position().returning(|| Ok((0, 0)));
         mock.expect_flush().once().returning(|| Ok(()));
 
-        PrinterImpl::new(mock).unwrap().flush().unwrap();
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .flush()
+            .unwrap();
+    }
+
+    #[test]
+    fn enter_alternate_screen() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(EnterAlternateScreen))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .enter_alternate_screen()
+            .unwrap();
+    }
+
+    #[test]
+    fn leave_alternate_screen() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(LeaveAlternateScreen))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Off)
+            .unwrap()
+            .leave_alternate_screen()
+            .unwrap();
+    }
+
+    #[test]
+    fn update_on_queues() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_queue()
+            .with(eq(BeginSynchronizedUpdate))
+            .once()
+            .returning(|_| Ok(()));
+        mock.expect_queue()
+            .with(eq(EndSynchronizedUpdate))
+            .once()
+            .returning(|_| Ok(()));
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::On).unwrap();
+
+        printer.begin_update().unwrap();
+        printer.end_update().unwrap();
+    }
+
+    #[test]
+    fn update_off_does_not_queue() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_supports_synchronized_update().never();
+
+        let mut printer = PrinterImpl::new(mock, SyncOutput::Off).unwrap();
+
+        printer.begin_update().unwrap();
+        printer.end_update().unwrap();
+    }
+
+    #[test]
+    fn update_auto_supported_queues() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_supports_synchronized_update()
+            .return_const(true);
+        mock.expect_queue()
+            .with(eq(BeginSynchronizedUpdate))
+            .once()
+            .returning(|_| Ok(()));
+
+        PrinterImpl::new(mock, SyncOutput::Auto)
+            .unwrap()
+            .begin_update()
+            .unwrap();
+    }
+
+    #[test]
+    fn update_auto_unsupported_does_not_queue() {
+        let mut mock = MockTerminal::new();
+        mock.expect_position().returning(|| Ok((0, 0)));
+        mock.expect_supports_synchronized_update()
+            .return_const(false);
+
+        PrinterImpl::new(mock, SyncOutput::Auto)
+            .unwrap()
+            .begin_update()
+            .unwrap();
     }
 }
@@ -1,9 +1,11 @@
+use crate::buffer::{Buffer, Cell};
 use crate::convert::{CharSample, Converter};
 use crate::pattern::*;
 use crate::Error;
 use crate::Printer;
 use crate::Vector;
-use crossterm::style::Color;
+use crossterm::style::{Attributes, Color};
+use rayon::prelude::*;
 
 /// A renderer for an animation.
 #[cfg_attr(test, mockall::automock)]
@@ -12,26 +14,112 @@ pub trait Renderer {
     fn render(&mut self, step: f32) -> Result<(), Error>;
 }
 
+/// The way terminal cells are drawn.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug, Default)]
+pub enum RenderMode {
+    /// Draw one sample per cell using the chars.
+    #[default]
+    Char,
+    /// Draw two samples per cell using half blocks without attributes.
+    HalfBlock,
+    /// Draw eight samples per cell using braille dots.
+    Braille,
+}
+
+/// What is left on the terminal after the animation.
+#[derive(clap::ValueEnum, Copy, Clone, PartialEq, Debug, Default)]
+pub enum Finish {
+    /// Clear the screen and the scrollback.
+    #[default]
+    Purge,
+    /// Clear the screen but keep the scrollback.
+    ClearVisible,
+    /// Keep the last frame and move the cursor below it.
+    Keep,
+    /// Keep the last frame and move the cursor back to where it was.
+    Restore,
+}
+
 /// The implementation of [Renderer].
 pub struct RendererImpl<T1, T2, T3: Printer> {
     sampler: T1,
     converter: T2,
     printer: T3,
+    mode: RenderMode,
+    alternate_screen: bool,
+    finish: Finish,
+    origin: (u16, u16),
+    buffer: Buffer,
 }
 
 impl<T1, T2, T3: Printer> RendererImpl<T1, T2, T3> {
-    pub fn new(sampler: T1, converter: T2, mut printer: T3) -> Result<Self, Error> {
-        printer.hide_cursor()?;
+    pub fn new(
+        sampler: T1,
+        converter: T2,
+        mut printer: T3,
+        mode: RenderMode,
+        alternate_screen: bool,
+        finish: Finish,
+    ) -> Result<Self, Error> {
+        let origin = printer.position();
+
+        if alternate_screen {
+            printer.enter_alternate_screen()?;
+        }
+        // Without a renderer there is no drop which could undo the setup.
+        if let Err(err) = printer.hide_cursor() {
+            if alternate_screen {
+                printer.leave_alternate_screen().ok();
+                printer.flush().ok();
+            }
+            return Err(err);
+        }
 
         Ok(Self {
             sampler,
             converter,
             printer,
+            mode,
+            alternate_screen,
+            finish,
+            origin,
+            buffer: Buffer::new(0, 0),
         })
     }
 }
 
-impl<T1: SamplerFactory, T2: Converter, T3: Printer> Renderer for RendererImpl<T1, T2, T3> {
+impl<T1, T2, T3: Printer> RendererImpl<T1, T2, T3> {
+    /// Reallocates the buffer and clears the screen if the terminal was resized.
+    fn resize(&mut self, width: u16, height: u16) -> Result<(), Error> {
+        // The content of the terminal is unknown before the first frame.
+        if self.buffer.size() == (0, 0) {
+            self.buffer = Buffer::new(width, height);
+            return Ok(());
+        }
+        // The terminal reflows its content on resize, so everything is redrawn.
+        self.printer.set_attributes(Attributes::default())?;
+        self.printer.set_background(Color::Reset)?;
+        self.printer.clear_screen()?;
+        self.buffer = Buffer::cleared(width, height);
+        Ok(())
+    }
+
+    /// Prints a cell unless the terminal already shows it.
+    fn draw(&mut self, x: u16, y: u16, cell: Cell) -> Result<(), Error> {
+        if !self.buffer.update(x, y, cell) {
+            return Ok(());
+        }
+        self.printer.move_to(x, y)?;
+        self.printer.set_attributes(cell.attributes)?;
+        if let Some(foreground) = cell.foreground {
+            self.printer.set_foreground(foreground)?;
+        }
+        self.printer.set_background(cell.background)?;
+        self.printer.print(cell.char)
+    }
+}
+
+impl<T1: SamplerFactory, T2: Converter + Sync, T3: Printer> Renderer for RendererImpl<T1, T2, T3> {
     fn render(&mut self, step: f32) -> Result<(), Error> {
         let (width, height) = self.printer.size()?;
         let config = Config {
@@ -40,37 +128,186 @@ impl<T1: SamplerFactory, T2: Converter, T3: Printer> Renderer for RendererImpl<T
         };
         let sampler = self.sampler.create(&config);
 
-        for y in 0..height {
-            for x in 0..width {
-                let pos = Vector::from_terminal(x, y);
-
-                match self.converter.char(sampler.char(pos)) {
-                    CharSample::Draw(char) => {
-                        let color = self.converter.color(sampler.color(pos));
-
-                        self.printer.move_to(x, y)?;
-                        self.printer.set_foreground(color)?;
-                        self.printer.print(char)?;
-                    }
-                    CharSample::Clear => {
-                        self.printer.move_to(x, y)?;
-                        self.printer.print(' ')?;
-                    }
-                    CharSample::Keep => (),
+        self.printer.begin_update()?;
+
+        // The update is ended on errors too, otherwise the terminal stays frozen.
+        let frame = self.draw_frame(&sampler, width, height);
+        let end = self.printer.end_update();
+        let flush = self.printer.flush();
+
+        frame.and(end).and(flush)
+    }
+}
+
+impl<T1: SamplerFactory, T2: Converter + Sync, T3: Printer> RendererImpl<T1, T2, T3> {
+    /// Samples and prints all cells of a frame.
+    fn draw_frame(&mut self, sampler: &T1::Sampler, width: u16, height: u16) -> Result<(), Error> {
+        if self.buffer.size() != (width, height) {
+            self.resize(width, height)?;
+        }
+        // The rows are sampled in parallel and printed in order afterwards.
+        let cells = CellSampler {
+            sampler,
+            converter: &self.converter,
+            buffer: &self.buffer,
+            mode: self.mode,
+        };
+        let rows: Vec<_> = (0..height)
+            .into_par_iter()
+            .map(|y| cells.row(width, y))
+            .collect();
+
+        for (y, row) in (0..height).zip(rows) {
+            for (x, cell) in (0..width).zip(row) {
+                if let Some(cell) = cell {
+                    self.draw(x, y, cell)?;
                 }
             }
         }
-        self.printer.flush()
+        Ok(())
+    }
+}
+
+/// Samples the cells of a frame.
+struct CellSampler<'a, T1, T2> {
+    sampler: &'a T1,
+    converter: &'a T2,
+    buffer: &'a Buffer,
+    mode: RenderMode,
+}
+
+impl<T1: Sampler, T2: Converter> CellSampler<'_, T1, T2> {
+    /// Samples all cells of a row.
+    fn row(&self, width: u16, y: u16) -> Vec<Option<Cell>> {
+        (0..width)
+            .map(|x| match self.mode {
+                RenderMode::Char => self.char(x, y),
+                RenderMode::HalfBlock => self.half_block(x, y),
+                RenderMode::Braille => self.braille(x, y),
+            })
+            .collect()
+    }
+
+    /// Samples a single cell with one sample.
+    fn char(&self, x: u16, y: u16) -> Option<Cell> {
+        let level = self.sampler.char(Vector::from_terminal(x, y));
+        let sample = self.converter.char(level);
+
+        self.cell(x, y, level, sample)
+    }
+
+    /// Samples a single cell with a 2x4 grid of samples.
+    fn braille(&self, x: u16, y: u16) -> Option<Cell> {
+        let pos = Vector::from_terminal(x, y);
+        let mut levels = [0.0; 8];
+
+        for (i, level) in levels.iter_mut().enumerate() {
+            let dx = (i / 4) as f32 * 0.5;
+            let dy = (i % 4) as f32 * 0.5;
+
+            *level = self.sampler.char(Vector::new(pos.x + dx, pos.y + dy));
+        }
+        let sample = self.converter.braille(&levels);
+
+        self.cell(x, y, levels[0], sample)
+    }
+
+    /// Converts a [CharSample] into a [Cell] colored by the fill and
+    /// background pattern with the attributes for the char level.
+    fn cell(&self, x: u16, y: u16, level: f32, sample: CharSample) -> Option<Cell> {
+        let pos = Vector::from_terminal(x, y);
+
+        match sample {
+            CharSample::Draw(char) => {
+                let attributes = self.converter.attributes(level);
+                let color = self.converter.color(self.sampler.color(pos));
+                let background = match self.sampler.background(pos) {
+                    Some(level) => self.converter.background(level),
+                    None => Color::Reset,
+                };
+                Some(Cell::new(char, Some(color), background, attributes))
+            }
+            CharSample::Clear => Some(Cell::empty()),
+            CharSample::Keep => None,
+        }
+    }
+
+    /// Samples a single cell with an upper and a lower sample.
+    ///
+    /// A kept half shows the same color as in the previous frame.
+    fn half_block(&self, x: u16, y: u16) -> Option<Cell> {
+        let upper = Vector::from_terminal(x, y);
+        let lower = Vector::new(upper.x, upper.y + 1.0);
+        let upper_char = self.converter.char(self.sampler.char(upper));
+        let lower_char = self.converter.char(self.sampler.char(lower));
+
+        if upper_char == CharSample::Keep && lower_char == CharSample::Keep {
+            return None;
+        }
+        let (upper_kept, lower_kept) = halves(self.buffer.get(x, y));
+        let color = |char, pos, kept| match char {
+            CharSample::Draw(_) => Some(self.converter.color(self.sampler.color(pos))),
+            CharSample::Clear => None,
+            CharSample::Keep => kept,
+        };
+        let (char, foreground, background) = match (
+            color(upper_char, upper, upper_kept),
+            color(lower_char, lower, lower_kept),
+        ) {
+            (Some(upper), Some(lower)) if upper == lower => ('█', Some(upper), Color::Reset),
+            (Some(upper), Some(lower)) => ('▀', Some(upper), lower),
+            (Some(upper), None) => ('▀', Some(upper), Color::Reset),
+            (None, Some(lower)) => ('▄', Some(lower), Color::Reset),
+            (None, None) => (' ', None, Color::Reset),
+        };
+        Some(Cell::new(
+            char,
+            foreground,
+            background,
+            Attributes::default(),
+        ))
+    }
+}
+
+/// Returns the colors of the upper and lower half of a half block cell.
+fn halves(cell: Option<Cell>) -> (Option<Color>, Option<Color>) {
+    let Some(cell) = cell else {
+        return (None, None);
+    };
+    let background = Some(cell.background).filter(|color| *color != Color::Reset);
+
+    match cell.char {
+        '█' => (cell.foreground, cell.foreground),
+        '▀' => (cell.foreground, background),
+        '▄' => (background, cell.foreground),
+        _ => (background, background),
     }
 }
 
 impl<T1, T2, T3: Printer> Drop for RendererImpl<T1, T2, T3> {
     fn drop(&mut self) {
         // Errors while dropping the renderer can be safely ignored.
-        self.printer.move_to(0, 0).ok();
+        let (x, y) = match self.finish {
+            Finish::Purge | Finish::ClearVisible => (0, 0),
+            Finish::Keep => (0, self.buffer.size().1.saturating_sub(1)),
+            Finish::Restore => self.origin,
+        };
+        self.printer.move_to(x, y).ok();
+        self.printer.set_attributes(Attributes::default()).ok();
         self.printer.set_foreground(Color::Reset).ok();
+        self.printer.set_background(Color::Reset).ok();
         self.printer.show_cursor().ok();
-        self.printer.clear().ok();
+        if self.alternate_screen {
+            self.printer.leave_alternate_screen().ok();
+        } else {
+            match self.finish {
+                Finish::Purge => self.printer.clear().ok(),
+                Finish::ClearVisible => self.printer.clear_screen().ok(),
+                // The shell prompt is printed on the line below the frame.
+                Finish::Keep => self.printer.new_line().ok(),
+                Finish::Restore => None,
+            };
+        }
         self.printer.flush().ok();
     }
 }
@@ -82,7 +319,7 @@ mod test {
     use crate::pattern::MockSampler;
     use crate::pattern::MockSamplerFactory;
     use crate::MockPrinter;
-    use crossterm::style::Color;
+    use crossterm::style::{Attribute, Color};
     use mockall::predicate::eq;
     use mockall::Sequence;
 
@@ -91,18 +328,68 @@ mod test {
         let factory = MockSamplerFactory::new();
         let converter = MockConverter::new();
         let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
 
         // Constructor
         printer.expect_hide_cursor().once().returning(|| Ok(()));
 
         // Drop
         printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
         printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
         printer.expect_show_cursor().returning(|| Ok(()));
         printer.expect_clear().returning(|| Ok(()));
         printer.expect_flush().returning(|| Ok(()));
 
-        drop(RendererImpl::new(factory, converter, printer));
+        drop(RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        ));
+    }
+
+    #[test]
+    fn new_error_leaves_alternate_screen() {
+        let factory = MockSamplerFactory::new();
+        let converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        let seq = &mut Sequence::new();
+        printer.expect_position().return_const((0, 0));
+
+        printer
+            .expect_enter_alternate_screen()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_hide_cursor()
+            .once()
+            .returning(|| Err(Error::from("broken pipe")))
+            .in_sequence(seq);
+        printer
+            .expect_leave_alternate_screen()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        let renderer = RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            true,
+            Finish::Purge,
+        );
+        assert!(renderer.is_err());
     }
 
     #[test]
@@ -110,6 +397,7 @@ mod test {
         let mut sampler = MockSamplerFactory::new();
         let mut converter = MockConverter::new();
         let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
 
         printer.expect_size().returning(|| Ok((2, 2)));
         sampler.expect_create().returning(|_| {
@@ -147,6 +435,14 @@ mod test {
                 .with(eq(Vector::new(1.0, 2.0)))
                 .return_const(8.0);
             sampler
+                .expect_background()
+                .with(eq(Vector::new(0.0, 2.0)))
+                .return_const(None);
+            sampler
+                .expect_background()
+                .with(eq(Vector::new(1.0, 2.0)))
+                .return_const(Some(9.0));
+            sampler
         });
         converter
             .expect_char()
@@ -172,6 +468,18 @@ mod test {
             .expect_color()
             .with(eq(8.0))
             .return_const(Color::Blue);
+        converter
+            .expect_background()
+            .with(eq(9.0))
+            .return_const(Color::Yellow);
+        converter
+            .expect_attributes()
+            .with(eq(3.0))
+            .return_const(Attributes::from(Attribute::Bold));
+        converter
+            .expect_attributes()
+            .with(eq(4.0))
+            .return_const(Attributes::default());
 
         let seq = &mut Sequence::new();
 
@@ -183,12 +491,29 @@ mod test {
             .in_sequence(seq);
 
         // Rendering
+        printer
+            .expect_begin_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_move_to()
             .once()
             .with(eq(1), eq(0))
             .returning(|_, _| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Reset))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_print()
             .once()
@@ -201,12 +526,24 @@ mod test {
             .with(eq(0), eq(1))
             .returning(|_, _| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::from(Attribute::Bold)))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_set_foreground()
             .once()
             .with(eq(Color::Red))
             .returning(|_| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Reset))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_print()
             .once()
@@ -219,18 +556,35 @@ mod test {
             .with(eq(1), eq(1))
             .returning(|_, _| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_set_foreground()
             .once()
             .with(eq(Color::Blue))
             .returning(|_| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Yellow))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_print()
             .once()
             .with(eq('X'))
             .returning(|_| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_end_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_flush()
             .once()
@@ -243,12 +597,24 @@ mod test {
             .once()
             .returning(|_, _| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_set_foreground()
             .with(eq(Color::Reset))
             .once()
             .returning(|_| Ok(()))
             .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .with(eq(Color::Reset))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
         printer
             .expect_show_cursor()
             .once()
@@ -265,7 +631,15 @@ mod test {
             .returning(|| Ok(()))
             .in_sequence(seq);
 
-        let mut renderer = RendererImpl::new(sampler, converter, printer).unwrap();
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
 
         renderer.render(0.0).unwrap();
     }
@@ -275,6 +649,7 @@ mod test {
         let mut sampler = MockSamplerFactory::new();
         let mut converter = MockConverter::new();
         let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
 
         sampler.expect_create().returning(|_| {
             let mut sampler = MockSampler::new();
@@ -283,6 +658,8 @@ mod test {
         });
         converter.expect_char().return_const(CharSample::Keep);
         printer.expect_size().returning(|| Ok((3, 2)));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
         printer.expect_flush().returning(|| Ok(()));
 
         // Constructor
@@ -290,56 +667,821 @@ mod test {
 
         // Drop
         printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
         printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
         printer.expect_show_cursor().returning(|| Ok(()));
         printer.expect_clear().returning(|| Ok(()));
         printer.expect_flush().returning(|| Ok(()));
 
-        let mut renderer = RendererImpl::new(sampler, converter, printer).unwrap();
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
 
         renderer.render(0.8).unwrap();
     }
 
     #[test]
-    fn end() {
-        let factory = MockSamplerFactory::new();
-        let converter = MockConverter::new();
+    fn render_error_ends_update() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
         let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
 
-        let seq = &mut Sequence::new();
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().return_const(0.0);
+            sampler
+        });
+        converter.expect_char().return_const(CharSample::Clear);
+        printer.expect_size().returning(|| Ok((1, 1)));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+        printer
+            .expect_print()
+            .returning(|_| Err(Error::from("broken pipe")));
 
-        // Constructor
+        // The update is ended although printing failed.
+        printer.expect_end_update().once().returning(|| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        assert!(renderer.render(0.5).is_err());
+    }
+
+    #[test]
+    fn render_unchanged_cells_skipped() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        sampler.expect_create().returning(|config| {
+            let step = config.step;
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().returning(move |pos| pos.x * step);
+            sampler.expect_color().return_const(0.0);
+            sampler.expect_background().return_const(None);
+            sampler
+        });
+        converter
+            .expect_char()
+            .returning(|level| CharSample::Draw(if level < 1.0 { 'a' } else { 'b' }));
+        converter.expect_color().return_const(Color::Red);
+        converter
+            .expect_attributes()
+            .return_const(Attributes::default());
+        printer.expect_size().returning(|| Ok((2, 1)));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
         printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
 
-        // Drop
-        printer
-            .expect_move_to()
-            .with(eq(0), eq(0))
-            .once()
-            .returning(|_, _| Ok(()))
-            .in_sequence(seq);
-        printer
-            .expect_set_foreground()
-            .with(eq(Color::Reset))
-            .once()
-            .returning(|_| Ok(()))
-            .in_sequence(seq);
+        // Only the second cell changes between the frames.
         printer
-            .expect_show_cursor()
-            .once()
-            .returning(|| Ok(()))
-            .in_sequence(seq);
+            .expect_print()
+            .with(eq('a'))
+            .times(2)
+            .returning(|_| Ok(()));
         printer
-            .expect_clear()
+            .expect_print()
+            .with(eq('b'))
             .once()
-            .returning(|| Ok(()))
-            .in_sequence(seq);
+            .returning(|_| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.5).unwrap();
+        renderer.render(1.0).unwrap();
+    }
+
+    #[test]
+    fn render_resize_clears() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+        let mut sizes = vec![(3, 1), (3, 1), (2, 1)];
+
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().return_const(0.0);
+            sampler
+        });
+        converter.expect_char().return_const(CharSample::Keep);
         printer
-            .expect_flush()
-            .once()
-            .returning(|| Ok(()))
-            .in_sequence(seq);
+            .expect_size()
+            .returning(move || Ok(sizes.pop().unwrap()));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
+
+        // Only the second frame has a different size.
+        printer.expect_clear_screen().once().returning(|| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.1).unwrap();
+        renderer.render(0.2).unwrap();
+        renderer.render(0.3).unwrap();
+    }
+
+    #[test]
+    fn render_resize_redraws() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+        let mut sizes = vec![(1, 2), (1, 1)];
 
-        drop(RendererImpl::new(factory, converter, printer));
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().return_const(0.0);
+            sampler.expect_color().return_const(0.0);
+            sampler.expect_background().return_const(None);
+            sampler
+        });
+        converter.expect_char().return_const(CharSample::Draw('x'));
+        converter.expect_color().return_const(Color::Red);
+        converter
+            .expect_attributes()
+            .return_const(Attributes::default());
+        printer
+            .expect_size()
+            .returning(move || Ok(sizes.pop().unwrap()));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
+        printer.expect_clear_screen().returning(|| Ok(()));
+
+        // The unchanged cell is drawn again after the resize.
+        printer
+            .expect_print()
+            .with(eq('x'))
+            .times(3)
+            .returning(|_| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.1).unwrap();
+        renderer.render(0.2).unwrap();
+    }
+
+    #[test]
+    fn render_half_block() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        printer.expect_size().returning(|| Ok((4, 1)));
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().returning(|pos| pos.x * 10.0 + pos.y);
+            sampler.expect_color().returning(|pos| pos.x * 10.0 + pos.y);
+            sampler
+        });
+        converter
+            .expect_char()
+            .returning(|level| match level as u32 {
+                0 | 1 | 31 => CharSample::Keep,
+                30 => CharSample::Clear,
+                _ => CharSample::Draw('x'),
+            });
+        converter
+            .expect_color()
+            .returning(|level| match level as u32 {
+                21 => Color::Blue,
+                _ => Color::Red,
+            });
+
+        let seq = &mut Sequence::new();
+
+        // Constructor
+        printer
+            .expect_hide_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        // Rendering
+        printer
+            .expect_begin_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_move_to()
+            .once()
+            .with(eq(1), eq(0))
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_foreground()
+            .once()
+            .with(eq(Color::Red))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Reset))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_print()
+            .once()
+            .with(eq('█'))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_move_to()
+            .once()
+            .with(eq(2), eq(0))
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_foreground()
+            .once()
+            .with(eq(Color::Red))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Blue))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_print()
+            .once()
+            .with(eq('▀'))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_move_to()
+            .once()
+            .with(eq(3), eq(0))
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Reset))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_print()
+            .once()
+            .with(eq(' '))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_end_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        // Drop
+        printer
+            .expect_move_to()
+            .once()
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_foreground()
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_show_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_clear()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::HalfBlock,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.0).unwrap();
+    }
+
+    #[test]
+    fn render_half_block_keeps_half() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        printer.expect_size().returning(|| Ok((1, 1)));
+        sampler.expect_create().returning(|config| {
+            let step = config.step;
+            let mut sampler = MockSampler::new();
+            sampler
+                .expect_char()
+                .returning(move |pos| step * 10.0 + pos.y);
+            sampler
+                .expect_color()
+                .returning(move |pos| step * 10.0 + pos.y);
+            sampler
+        });
+        converter
+            .expect_char()
+            .returning(|level| match level as u32 {
+                11 => CharSample::Keep,
+                _ => CharSample::Draw('x'),
+            });
+        converter
+            .expect_color()
+            .returning(|level| match level as u32 {
+                0 => Color::Red,
+                1 => Color::Blue,
+                _ => Color::Green,
+            });
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
+        printer
+            .expect_print()
+            .with(eq('▀'))
+            .times(2)
+            .returning(|_| Ok(()));
+
+        // The lower half keeps its color in the second frame.
+        printer
+            .expect_set_background()
+            .with(eq(Color::Blue))
+            .times(2)
+            .returning(|_| Ok(()));
+        printer
+            .expect_set_background()
+            .with(eq(Color::Reset))
+            .once()
+            .returning(|_| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::HalfBlock,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.0).unwrap();
+        renderer.render(1.0).unwrap();
+    }
+
+    #[test]
+    fn render_braille() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        printer.expect_size().returning(|| Ok((1, 1)));
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().returning(|pos| pos.x * 10.0 + pos.y);
+            sampler
+                .expect_color()
+                .with(eq(Vector::new(0.0, 0.0)))
+                .return_const(0.3);
+            sampler.expect_background().return_const(None);
+            sampler
+        });
+        converter
+            .expect_braille()
+            .with(eq([0.0, 0.5, 1.0, 1.5, 5.0, 5.5, 6.0, 6.5]))
+            .return_const(CharSample::Draw('\u{28FF}'));
+        converter
+            .expect_color()
+            .with(eq(0.3))
+            .return_const(Color::Green);
+        converter
+            .expect_attributes()
+            .with(eq(0.0))
+            .return_const(Attributes::from(Attribute::Underlined));
+
+        let seq = &mut Sequence::new();
+
+        // Constructor
+        printer
+            .expect_hide_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        // Rendering
+        printer
+            .expect_begin_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_move_to()
+            .once()
+            .with(eq(0), eq(0))
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::from(Attribute::Underlined)))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_foreground()
+            .once()
+            .with(eq(Color::Green))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .once()
+            .with(eq(Color::Reset))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_print()
+            .once()
+            .with(eq('\u{28FF}'))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_end_update()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        // Drop
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_clear().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Braille,
+            false,
+            Finish::Purge,
+        )
+        .unwrap();
+
+        renderer.render(0.0).unwrap();
+    }
+
+    #[test]
+    fn end() {
+        let factory = MockSamplerFactory::new();
+        let converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        let seq = &mut Sequence::new();
+
+        // Constructor
+        printer.expect_hide_cursor().returning(|| Ok(()));
+
+        // Drop
+        printer
+            .expect_move_to()
+            .with(eq(0), eq(0))
+            .once()
+            .returning(|_, _| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_attributes()
+            .once()
+            .with(eq(Attributes::default()))
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_foreground()
+            .with(eq(Color::Reset))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_set_background()
+            .with(eq(Color::Reset))
+            .once()
+            .returning(|_| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_show_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_clear()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        drop(RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Purge,
+        ));
+    }
+
+    #[test]
+    fn end_alternate_screen() {
+        let factory = MockSamplerFactory::new();
+        let converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((0, 0));
+
+        let seq = &mut Sequence::new();
+
+        // Constructor
+        printer
+            .expect_enter_alternate_screen()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_hide_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+
+        // Drop
+        printer.expect_move_to().returning(|_, _| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer
+            .expect_show_cursor()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_leave_alternate_screen()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer
+            .expect_flush()
+            .once()
+            .returning(|| Ok(()))
+            .in_sequence(seq);
+        printer.expect_clear().never();
+
+        drop(RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            true,
+            Finish::Purge,
+        ));
+    }
+
+    #[test]
+    fn end_clear_visible() {
+        let factory = MockSamplerFactory::new();
+        let converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((4, 2));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+
+        printer
+            .expect_move_to()
+            .with(eq(0), eq(0))
+            .once()
+            .returning(|_, _| Ok(()));
+        printer.expect_clear_screen().once().returning(|| Ok(()));
+        printer.expect_clear().never();
+
+        drop(RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::ClearVisible,
+        ));
+    }
+
+    #[test]
+    fn end_keep() {
+        let mut sampler = MockSamplerFactory::new();
+        let mut converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((4, 2));
+        printer.expect_size().returning(|| Ok((3, 5)));
+        printer.expect_begin_update().returning(|| Ok(()));
+        printer.expect_end_update().returning(|| Ok(()));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+        sampler.expect_create().returning(|_| {
+            let mut sampler = MockSampler::new();
+            sampler.expect_char().return_const(0.0);
+            sampler
+        });
+        converter.expect_char().return_const(CharSample::Keep);
+
+        printer
+            .expect_move_to()
+            .with(eq(0), eq(4))
+            .once()
+            .returning(|_, _| Ok(()));
+        printer.expect_new_line().once().returning(|| Ok(()));
+        printer.expect_clear_screen().never();
+        printer.expect_clear().never();
+
+        let mut renderer = RendererImpl::new(
+            sampler,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Keep,
+        )
+        .unwrap();
+
+        renderer.render(1.0).unwrap();
+    }
+
+    #[test]
+    fn end_restore() {
+        let factory = MockSamplerFactory::new();
+        let converter = MockConverter::new();
+        let mut printer = MockPrinter::new();
+        printer.expect_position().return_const((4, 2));
+        printer.expect_hide_cursor().returning(|| Ok(()));
+        printer.expect_set_attributes().returning(|_| Ok(()));
+        printer.expect_set_foreground().returning(|_| Ok(()));
+        printer.expect_set_background().returning(|_| Ok(()));
+        printer.expect_show_cursor().returning(|| Ok(()));
+        printer.expect_flush().returning(|| Ok(()));
+
+        printer
+            .expect_move_to()
+            .with(eq(4), eq(2))
+            .once()
+            .returning(|_, _| Ok(()));
+        printer.expect_clear_screen().never();
+        printer.expect_clear().never();
+
+        drop(RendererImpl::new(
+            factory,
+            converter,
+            printer,
+            RenderMode::Char,
+            false,
+            Finish::Restore,
+        ));
     }
 }
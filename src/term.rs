@@ -1,6 +1,10 @@
 use crate::Error;
 use crossterm::{Command, QueueableCommand};
 use std::io::Write;
+use std::time::Duration;
+
+/// The time to wait for the answer of the terminal to a query.
+const QUERY_TIMEOUT: Duration = Duration::from_millis(100);
 
 /// A stub for OS calls and crossterm functions.
 #[cfg_attr(test, mockall::automock)]
@@ -13,6 +17,8 @@ pub trait Terminal {
     fn size(&self) -> Result<(u16, u16), Error>;
     /// Returns the current cursor position.
     fn position(&self) -> Result<(u16, u16), Error>;
+    /// Returns true if the terminal supports synchronized updates.
+    fn supports_synchronized_update(&self) -> bool;
 }
 
 /// The implementation of [Terminal].
@@ -44,4 +50,170 @@ impl<T: Write> Terminal for TerminalImpl<T> {
     fn position(&self) -> Result<(u16, u16), Error> {
         Ok(crossterm::cursor::position()?)
     }
+
+    fn supports_synchronized_update(&self) -> bool {
+        match query_synchronized_update(QUERY_TIMEOUT) {
+            Some(supported) => supported,
+            None => detect_synchronized_update(|name| std::env::var(name).ok()),
+        }
+    }
+}
+
+/// Asks the terminal with DECRQM whether it supports the synchronized update mode 2026.
+///
+/// The query is followed by a request for the primary device attributes,
+/// which every terminal answers, so the timeout only passes without a terminal.
+/// Returns none if the terminal didn't answer.
+#[cfg(unix)]
+fn query_synchronized_update(timeout: Duration) -> Option<bool> {
+    use std::fs::OpenOptions;
+    use std::os::unix::io::AsRawFd;
+
+    let mut tty = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let fd = tty.as_raw_fd();
+    // SAFETY: The termios structs are plain data and the descriptor is open.
+    let mut original = unsafe { std::mem::zeroed::<libc::termios>() };
+
+    if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+        return None;
+    }
+    let mut raw = original;
+    unsafe { libc::cfmakeraw(&mut raw) };
+
+    if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+        return None;
+    }
+    let reply = tty
+        .write_all(b"\x1b[?2026$p\x1b[c")
+        .and_then(|_| tty.flush())
+        .map(|_| read_reply(fd, timeout));
+    unsafe { libc::tcsetattr(fd, libc::TCSANOW, &original) };
+
+    parse_reply(&reply.ok()?)
+}
+
+/// Synchronized updates are never detected by a query on this platform.
+#[cfg(not(unix))]
+fn query_synchronized_update(_: Duration) -> Option<bool> {
+    None
+}
+
+/// Reads the answer of the terminal until the device attributes arrive or the time is up.
+#[cfg(unix)]
+fn read_reply(fd: i32, timeout: Duration) -> Vec<u8> {
+    let end = std::time::Instant::now() + timeout;
+    let mut reply = Vec::new();
+    let mut buffer = [0; 64];
+
+    while !reply.ends_with(b"c") {
+        let left = end.saturating_duration_since(std::time::Instant::now());
+        let mut poll = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: The pointers refer to live values of the given sizes.
+        if left.is_zero() || unsafe { libc::poll(&mut poll, 1, left.as_millis() as i32) } <= 0 {
+            break;
+        }
+        let read = unsafe { libc::read(fd, buffer.as_mut_ptr().cast(), buffer.len()) };
+
+        if read <= 0 {
+            break;
+        }
+        reply.extend_from_slice(&buffer[..read as usize]);
+    }
+    reply
+}
+
+/// Parses the answer of the terminal to the query.
+///
+/// A report of the mode 2026 as set or reset means support.
+/// Returns none if there is neither a report nor the device attributes.
+fn parse_reply(reply: &[u8]) -> Option<bool> {
+    let prefix = b"\x1b[?2026;";
+    let report = reply
+        .windows(prefix.len() + 3)
+        .find(|window| window.starts_with(prefix) && window.ends_with(b"$y"));
+
+    match report {
+        Some(report) => Some(matches!(report[prefix.len()], b'1' | b'2' | b'3')),
+        None if reply.ends_with(b"c") => Some(false),
+        None => None,
+    }
+}
+
+/// Detects support for synchronized updates from the environment variables
+/// `TERM` and `TERM_PROGRAM` of terminals known to implement it.
+///
+/// This is only used if the terminal doesn't answer the query.
+fn detect_synchronized_update(var: impl Fn(&str) -> Option<String>) -> bool {
+    let term = var("TERM").unwrap_or_default();
+    let program = var("TERM_PROGRAM").unwrap_or_default();
+
+    matches!(
+        term.as_str(),
+        "xterm-kitty" | "foot" | "foot-extra" | "alacritty" | "contour" | "wezterm"
+    ) || matches!(program.as_str(), "WezTerm" | "iTerm.app" | "contour")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_util::env;
+
+    #[test]
+    fn parse_reply_set() {
+        assert_eq!(Some(true), parse_reply(b"\x1b[?2026;2$y\x1b[?62;22c"));
+        assert_eq!(Some(true), parse_reply(b"\x1b[?2026;1$y\x1b[?1;2c"));
+        assert_eq!(Some(true), parse_reply(b"\x1b[?2026;3$y\x1b[?1;2c"));
+    }
+
+    #[test]
+    fn parse_reply_unsupported() {
+        assert_eq!(Some(false), parse_reply(b"\x1b[?2026;0$y\x1b[?62c"));
+        assert_eq!(Some(false), parse_reply(b"\x1b[?2026;4$y\x1b[?62c"));
+        assert_eq!(Some(false), parse_reply(b"\x1b[?62;22c"));
+    }
+
+    #[test]
+    fn parse_reply_none() {
+        assert_eq!(None, parse_reply(b""));
+        assert_eq!(None, parse_reply(b"\x1b[?20"));
+    }
+
+    #[test]
+    fn synchronized_update_kitty() {
+        assert!(detect_synchronized_update(env(&[("TERM", "xterm-kitty")])));
+    }
+
+    #[test]
+    fn synchronized_update_foot() {
+        assert!(detect_synchronized_update(env(&[("TERM", "foot")])));
+    }
+
+    #[test]
+    fn synchronized_update_wezterm() {
+        assert!(detect_synchronized_update(env(&[
+            ("TERM", "xterm-256color"),
+            ("TERM_PROGRAM", "WezTerm")
+        ])));
+    }
+
+    #[test]
+    fn synchronized_update_xterm() {
+        assert!(!detect_synchronized_update(env(&[(
+            "TERM",
+            "xterm-256color"
+        )])));
+    }
+
+    #[test]
+    fn synchronized_update_empty() {
+        assert!(!detect_synchronized_update(env(&[])));
+    }
 }
@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+/// Returns a lookup of environment variables backed by the given pairs.
+pub fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+    move |name| vars.get(name).cloned()
+}
@@ -17,7 +17,7 @@ pub struct Invert {
 
 impl PatternFactory for InvertFactory {
     fn create(&self, config: &Config) -> Box<dyn Pattern> {
-        let mut copy = config.clone();
+        let mut copy = *config;
         copy.step = 1.0 - config.step;
 
         Box::new(Invert::new(self.child.create(&copy)))
@@ -43,7 +43,7 @@ mod test {
             size: Vector::new(4.0, 2.0),
             step: 0.4,
         };
-        let mut output = input.clone();
+        let mut output = input;
         output.step = 0.6;
 
         let mut child = MockPatternFactory::new();
@@ -1,12 +1,14 @@
 //! Contains transformations to apply on top of patterns.
 
 mod invert;
+mod rotate;
 mod segment;
 mod shift;
 mod shrink;
 mod swap;
 
 pub use invert::*;
+pub use rotate::*;
 pub use segment::*;
 pub use shift::*;
 pub use shrink::*;
@@ -0,0 +1,138 @@
+use crate::pattern::*;
+use crate::Vector;
+
+/// A factory for [Rotate].
+///
+/// The angle advances by `speed` over the course of the animation.
+/// Resizes the terminal for the contained [Pattern] to the bounding box of the rotated area.
+#[derive(derive_more::Constructor)]
+pub struct RotateFactory {
+    child: Box<dyn PatternFactory>,
+    angle: f32,
+    speed: f32,
+}
+
+/// Rotates the coordinates around the center.
+#[derive(derive_more::Constructor)]
+pub struct Rotate {
+    child: Box<dyn Pattern>,
+    center: Vector,
+    child_center: Vector,
+    sin: f32,
+    cos: f32,
+}
+
+impl PatternFactory for RotateFactory {
+    fn create(&self, config: &Config) -> Box<dyn Pattern> {
+        let angle = self.angle + self.speed * config.step;
+        let (sin, cos) = angle.sin_cos();
+        let mut copy = *config;
+        copy.size = Vector::new(
+            (config.size.x * cos).abs() + (config.size.y * sin).abs(),
+            (config.size.x * sin).abs() + (config.size.y * cos).abs(),
+        );
+
+        Box::new(Rotate::new(
+            self.child.create(&copy),
+            config.size.center(),
+            copy.size.center(),
+            sin,
+            cos,
+        ))
+    }
+}
+
+impl Pattern for Rotate {
+    fn sample(&self, pos: Vector) -> f32 {
+        let pos = pos - self.center;
+        let rotated = Vector::new(
+            pos.x * self.cos + pos.y * self.sin,
+            pos.y * self.cos - pos.x * self.sin,
+        );
+        self.child.sample(rotated + self.child_center)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MockPatternFactory;
+    use approx::*;
+    use mockall::predicate::{eq, function};
+    use std::f32::consts::PI;
+
+    #[test]
+    fn create_config_correct() {
+        let input = Config {
+            size: Vector::new(4.0, 2.0),
+            step: 0.4,
+        };
+        let mut child = MockPatternFactory::new();
+        child
+            .expect_create()
+            .with(eq(input))
+            .once()
+            .returning(|_| Box::new(MockPattern::new()));
+
+        RotateFactory::new(Box::new(child), 0.0, 0.0).create(&input);
+    }
+
+    #[test]
+    fn create_config_rotated() {
+        let input = Config {
+            size: Vector::new(4.0, 2.0),
+            step: 0.5,
+        };
+        let mut child = MockPatternFactory::new();
+        child
+            .expect_create()
+            .with(function(|config: &Config| {
+                abs_diff_eq!(0.5, config.step)
+                    && abs_diff_eq!(2.0, config.size.x, epsilon = 0.01)
+                    && abs_diff_eq!(4.0, config.size.y, epsilon = 0.01)
+            }))
+            .once()
+            .returning(|_| Box::new(MockPattern::new()));
+
+        RotateFactory::new(Box::new(child), 0.0, PI).create(&input);
+    }
+
+    #[test]
+    fn sample_value_correct() {
+        let mut child = MockPatternFactory::new();
+        child.expect_create().returning(|_| {
+            let mut sampler = MockPattern::new();
+            sampler.expect_sample().return_const(0.4);
+            Box::new(sampler)
+        });
+
+        let sampler = RotateFactory::new(Box::new(child), 1.0, 0.0).create(&Config::default());
+
+        assert_abs_diff_eq!(0.4, sampler.sample(Vector::default()));
+    }
+
+    #[test]
+    fn sample_pos_correct() {
+        let config = Config {
+            size: Vector::new(4.0, 4.0),
+            ..Config::default()
+        };
+        let mut child = MockPatternFactory::new();
+        child.expect_create().once().returning(|_| {
+            let mut sampler = MockPattern::new();
+            sampler
+                .expect_sample()
+                .with(function(|pos: &Vector| {
+                    abs_diff_eq!(2.0, pos.x, epsilon = 0.01)
+                        && abs_diff_eq!(1.0, pos.y, epsilon = 0.01)
+                }))
+                .once()
+                .return_const(0.0);
+            Box::new(sampler)
+        });
+
+        let sampler = RotateFactory::new(Box::new(child), PI / 2.0, 0.0).create(&config);
+
+        sampler.sample(Vector::new(3.0, 2.0));
+    }
+}
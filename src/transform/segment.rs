@@ -25,7 +25,7 @@ impl Pattern for Segments {
     fn sample(&self, pos: Vector) -> f32 {
         let sample = self.child.sample(pos);
 
-        if 0.0 <= sample && sample < 1.0 {
+        if (0.0..1.0).contains(&sample) {
             sample * self.segments % 1.0
         } else {
             sample
@@ -17,7 +17,7 @@ pub struct Swap {
 
 impl PatternFactory for SwapFactory {
     fn create(&self, config: &Config) -> Box<dyn Pattern> {
-        let mut copy = config.clone();
+        let mut copy = *config;
         copy.size = config.size.swap();
 
         Box::new(Swap::new(self.child.create(&copy)))
@@ -43,7 +43,7 @@ mod test {
             size: Vector::new(4.0, 2.0),
             step: 0.4,
         };
-        let mut output = input.clone();
+        let mut output = input;
         output.size = Vector::new(2.0, 4.0);
 
         let mut child = MockPatternFactory::new();
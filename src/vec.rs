@@ -1,5 +1,5 @@
 /// A vector with a x-axis and y-axis.
-#[derive(Copy, Clone, PartialEq, Debug, Default, derive_more::Sub)]
+#[derive(Copy, Clone, PartialEq, Debug, Default, derive_more::Add, derive_more::Sub)]
 pub struct Vector {
     pub x: f32,
     pub y: f32,
@@ -31,6 +31,11 @@ impl Vector {
         self.x + self.y
     }
 
+    /// Returns the dot product.
+    pub fn dot(&self, other: &Vector) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
     /// Returns the center.
     pub fn center(&self) -> Vector {
         Self::new(self.x / 2.0, self.y / 2.0)
@@ -74,6 +79,11 @@ mod test {
         assert_abs_diff_eq!(11.0, Vector::new(3.0, 8.0).sum());
     }
 
+    #[test]
+    fn dot() {
+        assert_abs_diff_eq!(26.0, Vector::new(3.0, 4.0).dot(&Vector::new(2.0, 5.0)));
+    }
+
     #[test]
     fn center() {
         assert_eq!(Vector::new(4.0, 9.0), Vector::new(8.0, 18.0).center());